@@ -34,7 +34,9 @@ fn bench_from_axis_angle<T: Rotation3<f32>>(bh: &mut Bencher) {
 
     let mut rng = SmallRng::from_entropy();
 
-    let axis: Vec<_> = (0..LEN).map(|_| rng.gen::<Vector3<f32>>()).collect();
+    let axis: Vec<_> = (0..LEN)
+        .map(|_| Unit::try_normalize(rng.gen::<Vector3<f32>>()).unwrap_or(Unit::unit_x()))
+        .collect();
     let angle: Vec<_> = (0..LEN).map(|_| rng.gen::<Rad<f32>>()).collect();
     let mut i = 0;
 
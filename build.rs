@@ -2,16 +2,18 @@ use std::env;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
+use std::process::Command;
 use std::string::String;
 
 /// Generate the name of the swizzle function and what it returns.
+/// `symbols`: swizzle variables (e.g. "xyz"), optionally followed by the constants `0` and `1`
 /// NOTE: This function assumes that variables are in ASCII format
 #[cfg(feature = "swizzle")]
-fn gen_swizzle_nth<'a>(variables: &'a str, mut i: usize, upto: usize) -> Option<(String, String)> {
+fn gen_swizzle_nth<'a>(symbols: &'a str, mut i: usize, upto: usize) -> Option<(String, String)> {
     debug_assert!(i > 0); // zeroth permutation is empty
     let mut swizzle_impl = String::new();
     let mut swizzle = String::new();
-    let n = variables.len() + 1;
+    let n = symbols.len() + 1;
     for _ in 0..upto {
         if i == 0 {
             break;
@@ -19,9 +21,13 @@ fn gen_swizzle_nth<'a>(variables: &'a str, mut i: usize, upto: usize) -> Option<
         if i % n == 0 {
             return None;
         }
-        let c = variables.as_bytes()[i % n - 1] as char;
+        let c = symbols.as_bytes()[i % n - 1] as char;
         swizzle.push(c);
-        swizzle_impl.push_str(&format!("self.{}, ", c));
+        match c {
+            '0' => swizzle_impl.push_str("<$S as ::num_traits::Zero>::zero(), "),
+            '1' => swizzle_impl.push_str("<$S as ::num_traits::One>::one(), "),
+            _ => swizzle_impl.push_str(&format!("self.{}, ", c)),
+        }
         i = i / n;
     }
     Some((swizzle, swizzle_impl))
@@ -47,6 +53,60 @@ fn gen_swizzle_functions(variables: &'static str, upto: usize) -> String {
             ));
         }
     }
+
+    result.push_str(&gen_constant_swizzle_functions(variables, upto));
+
+    // Setters for swizzles of at least two distinct variables, such as `set_xz()`.
+    let nn = (variables.len() + 1).pow(variables.len() as u32);
+    for i in 1..nn {
+        if let Some((swizzle_name, _)) = gen_swizzle_nth(variables, i, variables.len()) {
+            let distinct = swizzle_name
+                .char_indices()
+                .all(|(j, c)| !swizzle_name[..j].contains(c));
+            if swizzle_name.len() < 2 || !distinct {
+                continue;
+            }
+            let dim = format!("{}", swizzle_name.len());
+            let assignments: String = swizzle_name
+                .chars()
+                .zip("xyzw".chars())
+                .map(|(c, field)| format!("self.{} = value.{}; ", c, field))
+                .collect();
+            result.push_str(&format!(
+                "
+        /// Swizzle operator that assigns the variables `{0}` from a type with dimension {2}.
+        #[inline] pub fn set_{0}(&mut self, value: $vector_type{2}<$S>) {{ {1}}}\n",
+                swizzle_name, assignments, dim
+            ));
+        }
+    }
+    result
+}
+
+/// A function that generates swizzle functions mixing in the constants `0` and `1`, such as
+/// `x0z()`, as a string.
+#[cfg(feature = "swizzle")]
+fn gen_constant_swizzle_functions(variables: &'static str, upto: usize) -> String {
+    let mut result = String::new();
+    // Names must start with a variable to be valid identifiers.
+    let symbols = format!("{}01", variables);
+    let nn = (symbols.len() + 1).pow(upto as u32);
+    for i in 1..nn {
+        if let Some((swizzle_name, swizzle_impl)) = gen_swizzle_nth(&symbols, i, upto) {
+            if swizzle_name.starts_with(|c: char| c.is_digit(2))
+                || !swizzle_name.contains(|c: char| c.is_digit(2))
+            {
+                continue;
+            }
+            let dim = format!("{}", swizzle_name.len());
+            result.push_str(&format!(
+                "
+        /// Swizzle operator that creates a new type with dimension {2} from variables and constants `{0}`.
+        #[inline] pub fn {0}(&self) -> $vector_type{2}<$S> where $S: ::num_traits::Zero + ::num_traits::One {{ $vector_type{2}::new({1}) }}\n",
+                swizzle_name, swizzle_impl, dim
+            ));
+        }
+    }
     result
 }
 
@@ -55,6 +115,18 @@ fn gen_swizzle_functions(_: &'static str, _: usize) -> String {
     String::new()
 }
 
+/// The minor version of the compiler, such as `34` for Rust 1.34.
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    let mut pieces = version.split('.');
+    if pieces.next() != Some("rustc 1") {
+        return None;
+    }
+    pieces.next()?.parse().ok()
+}
+
 /// This script generates the macro for building swizzle operators for multidimensional
 /// vectors and points. This macro is included in macros.rs
 fn main() {
@@ -98,4 +170,15 @@ macro_rules! impl_swizzle_functions {{
         .expect("Unable to create file that defines the swizzle operator macro.");
     f.write_all(data.as_bytes())
         .expect("Unable to write swizzle operator macro.");
+
+    // `std::convert::TryFrom` is stable from Rust 1.34, and the old nightly
+    // used for the "simd" feature predates it.
+    println!("cargo:rustc-check-cfg=cfg(has_try_from)");
+    let has_try_from = match rustc_minor_version() {
+        Some(minor) => minor >= 34,
+        None => true,
+    };
+    if has_try_from {
+        println!("cargo:rustc-cfg=has_try_from");
+    }
 }
@@ -0,0 +1,329 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Camera helpers for rendering cube maps, such as omnidirectional shadow
+//! maps and environment captures, cascaded shadow maps for directional
+//! lights, sub-pixel jitter for temporal anti-aliasing, and oblique near
+//! planes for reflections and portals.
+//!
+//! The view matrices are right-handed, like `Matrix4::look_at_dir`, and the
+//! projections can be turned into matrices for any `ClipSpace`.
+
+use num_traits::{cast, Float};
+use std::marker::PhantomData;
+
+use structure::*;
+
+use angle::Rad;
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use projection::{ClipSpace, DepthRange, Ortho, PerspectiveFov};
+use transform::Transform;
+use vector::{Vector2, Vector3, Vector4};
+
+/// A face of a cube map.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CubeFace {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+impl CubeFace {
+    /// All of the faces, in the order of the cube map layers.
+    pub const ALL: [CubeFace; 6] = [
+        CubeFace::PositiveX,
+        CubeFace::NegativeX,
+        CubeFace::PositiveY,
+        CubeFace::NegativeY,
+        CubeFace::PositiveZ,
+        CubeFace::NegativeZ,
+    ];
+
+    /// The direction from the center of the cube to the center of the face.
+    pub fn direction<S: BaseFloat>(self) -> Vector3<S> {
+        match self {
+            CubeFace::PositiveX => Vector3::unit_x(),
+            CubeFace::NegativeX => -Vector3::unit_x(),
+            CubeFace::PositiveY => Vector3::unit_y(),
+            CubeFace::NegativeY => -Vector3::unit_y(),
+            CubeFace::PositiveZ => Vector3::unit_z(),
+            CubeFace::NegativeZ => -Vector3::unit_z(),
+        }
+    }
+
+    /// The up direction of the face.
+    ///
+    /// This follows the orientation of cube map faces in OpenGL, Vulkan,
+    /// Direct3D and Metal, where the second texture coordinate of the side
+    /// faces points down.
+    pub fn up<S: BaseFloat>(self) -> Vector3<S> {
+        match self {
+            CubeFace::PositiveY => Vector3::unit_z(),
+            CubeFace::NegativeY => -Vector3::unit_z(),
+            _ => -Vector3::unit_y(),
+        }
+    }
+
+    /// The view matrix for rendering this face from `eye`.
+    pub fn view<S: BaseFloat>(self, eye: Point3<S>) -> Matrix4<S> {
+        Matrix4::look_at_dir(eye, self.direction(), self.up())
+    }
+}
+
+/// The view matrices for rendering the six faces of a cube map from `eye`,
+/// in the order of `CubeFace::ALL`.
+pub fn cube_face_views<S: BaseFloat>(eye: Point3<S>) -> [Matrix4<S>; 6] {
+    [
+        CubeFace::PositiveX.view(eye),
+        CubeFace::NegativeX.view(eye),
+        CubeFace::PositiveY.view(eye),
+        CubeFace::NegativeY.view(eye),
+        CubeFace::PositiveZ.view(eye),
+        CubeFace::NegativeZ.view(eye),
+    ]
+}
+
+/// The projection for rendering a face of a cube map, with a field of view
+/// of 90 degrees and a square aspect ratio.
+pub fn cube_face_projection<S: BaseFloat>(near: S, far: S) -> PerspectiveFov<S> {
+    PerspectiveFov {
+        fovy: Rad::turn_div_4(),
+        aspect: S::one(),
+        near: near,
+        far: far,
+    }
+}
+
+/// Split the view distance between `near` and `far` into `count` cascades,
+/// using the practical split scheme.
+///
+/// The scheme blends logarithmic splits, which keep the resolution of the
+/// shadow map constant relative to the view distance, with uniform splits,
+/// which avoid the first cascades becoming too small. A `lambda` of `1` gives
+/// logarithmic splits, and `0` uniform splits; `0.5` to `0.8` is common.
+///
+/// Returns the `count + 1` boundaries of the cascades, starting with `near`
+/// and ending with `far`.
+pub fn cascade_splits<S: BaseFloat>(near: S, far: S, count: usize, lambda: S) -> Vec<S> {
+    assert!(count > 0, "The number of cascades cannot be zero");
+    assert!(
+        near > S::zero() && far > near,
+        "The near and far distances must be positive and increasing, found: near: {:?}, far: {:?}",
+        near,
+        far
+    );
+
+    let count_s: S = cast(count).unwrap();
+    let mut splits: Vec<S> = (0..count + 1)
+        .map(|i| {
+            let t = cast::<_, S>(i).unwrap() / count_s;
+            let log = near * (far / near).powf(t);
+            let uniform = near + (far - near) * t;
+            lambda * log + (S::one() - lambda) * uniform
+        })
+        .collect();
+
+    // Avoid rounding errors at the ends.
+    splits[0] = near;
+    splits[count] = far;
+    splits
+}
+
+/// Fit an orthographic projection around a slice of a camera's view frustum,
+/// as seen from a directional light.
+///
+/// The slice lies between the view distances `near` and `far` of `camera`,
+/// whose view matrix is `view`. The returned projection is in the view space
+/// of the light given by `light_view`, and encloses the bounding sphere of
+/// the slice. Its size therefore only depends on `camera`, `near` and `far`,
+/// and its left, right, bottom and top planes are snapped outwards to whole
+/// texels of a shadow map with `resolution` texels on each side. Together this
+/// keeps shadow edges from shimmering when the camera moves or rotates.
+///
+/// The near and far planes enclose the slice only. Shadow casters between the
+/// light and the slice are not included, so these usually need to be extended
+/// towards the light.
+///
+/// Returns `None` if the view matrix is not invertible.
+pub fn fit_cascade<S: BaseFloat>(
+    camera: &PerspectiveFov<S>,
+    view: Matrix4<S>,
+    near: S,
+    far: S,
+    light_view: Matrix4<S>,
+    resolution: u32,
+) -> Option<Ortho<S>> {
+    assert!(
+        resolution > 1,
+        "The shadow map must be more than one texel wide"
+    );
+    let two: S = cast(2).unwrap();
+    let to_light = light_view * view.invert()?;
+    let tan_y = Rad::tan(camera.fovy / two);
+    let tan_x = tan_y * camera.aspect;
+
+    // Bound the slice by the smallest sphere through its near and far
+    // corners. Unlike a box around the corners, the size of the sphere does
+    // not change when the camera rotates, so neither does the texel size.
+    let k = tan_x * tan_x + tan_y * tan_y;
+    let center = (far + near) * (S::one() + k) / two;
+    let (center, radius) = if center < far {
+        let offset = center - near;
+        (center, Float::sqrt(offset * offset + k * near * near))
+    } else {
+        (far, Float::sqrt(k) * far)
+    };
+    let center = to_light.transform_point(Point3::new(S::zero(), S::zero(), -center));
+
+    // Leave room for one texel of snapping, so that the snapped projection
+    // still covers the slice with a texel size that fits the grid exactly.
+    let resolution: S = cast(resolution).unwrap();
+    let texel = two * radius / (resolution - S::one());
+    let left = Float::floor((center.x - radius) / texel) * texel;
+    let bottom = Float::floor((center.y - radius) / texel) * texel;
+
+    // The light looks down its negative z axis.
+    Some(Ortho {
+        left: left,
+        right: left + texel * resolution,
+        bottom: bottom,
+        top: bottom + texel * resolution,
+        near: -(center.z + radius),
+        far: -(center.z - radius),
+    })
+}
+
+/// Offset a projection matrix by a fraction of a pixel, for temporal
+/// anti-aliasing.
+///
+/// The `offset` is in pixels along the x and y axes of normalized device
+/// coordinates, and `size` is the size of the viewport in pixels. This works
+/// for both perspective and orthographic projections.
+pub fn jitter<S: BaseFloat>(
+    projection: Matrix4<S>,
+    offset: Vector2<S>,
+    size: Vector2<S>,
+) -> Matrix4<S> {
+    let two: S = cast(2).unwrap();
+    let offset = Vector3::new(two * offset.x / size.x, two * offset.y / size.y, S::zero());
+    Matrix4::from_translation(offset) * projection
+}
+
+/// Replace the near plane of a projection matrix by an arbitrary clip plane,
+/// as used for planar reflections and portals. The far plane is moved so that
+/// it still contains the far corner of the original view frustum.
+///
+/// This is [Lengyel's oblique view frustum][lengyel]. The `clip_plane` is in
+/// view space, given as `(a, b, c, d)` for the plane `ax + by + cz + d = 0`,
+/// with its normal pointing into the visible half-space, away from the eye.
+/// The `clip_space` must be the one that `projection` was created with.
+///
+/// Returns `None` if the projection matrix is not invertible.
+///
+/// [lengyel]: http://www.terathon.com/lengyel/Lengyel-Oblique.pdf
+pub fn oblique_near_plane<S: BaseFloat>(
+    projection: Matrix4<S>,
+    clip_plane: Vector4<S>,
+    clip_space: ClipSpace,
+) -> Option<Matrix4<S>> {
+    let inverse = projection.invert()?;
+    let (scale, far) = match (clip_space.depth_range, clip_space.reverse_z) {
+        (DepthRange::NegativeOneToOne, false) => (cast(2).unwrap(), S::one()),
+        (DepthRange::NegativeOneToOne, true) => (cast(2).unwrap(), -S::one()),
+        (DepthRange::ZeroToOne, false) => (S::one(), S::one()),
+        (DepthRange::ZeroToOne, true) => (S::one(), S::zero()),
+    };
+
+    // The corner of the frustum on the far plane opposite to the clip plane.
+    let plane = inverse.transpose() * clip_plane;
+    let corner = Vector4::new(plane.x.signum(), plane.y.signum(), far, S::one());
+    let corner = inverse * corner;
+    let c = clip_plane * (scale / clip_plane.dot(corner));
+
+    let w = projection.row(3);
+    let z = match (clip_space.depth_range, clip_space.reverse_z) {
+        (DepthRange::NegativeOneToOne, false) => c - w,
+        (DepthRange::ZeroToOne, false) => c,
+        (_, true) => w - c,
+    };
+
+    let mut m = projection;
+    m.x.z = z.x;
+    m.y.z = z.y;
+    m.z.z = z.z;
+    m.w.z = z.w;
+    Some(m)
+}
+
+/// Evaluate the Halton low-discrepancy sequence with the given base at
+/// `index`, giving a value in `[0, 1)`.
+pub fn halton<S: BaseFloat>(index: u32, base: u32) -> S {
+    let base_s: S = cast(base).unwrap();
+    let mut index = index;
+    let mut fraction = S::one();
+    let mut result = S::zero();
+    while index > 0 {
+        fraction /= base_s;
+        result += fraction * cast(index % base).unwrap();
+        index /= base;
+    }
+    result
+}
+
+/// An endless iterator over sub-pixel jitter offsets for temporal
+/// anti-aliasing, taken from the Halton (2, 3) sequence.
+///
+/// The offsets are in pixels, within `[-0.5, 0.5)`, and repeat after `period`
+/// items. They can be passed to `jitter`.
+#[derive(Clone, Debug)]
+pub struct HaltonJitter<S> {
+    index: u32,
+    period: u32,
+    phantom: PhantomData<S>,
+}
+
+impl<S> HaltonJitter<S> {
+    /// Create the sequence, repeating after `period` offsets. Common periods
+    /// are 8 or 16.
+    pub fn new(period: u32) -> HaltonJitter<S> {
+        assert!(period > 0, "The period of the sequence cannot be zero");
+        HaltonJitter {
+            index: 0,
+            period: period,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: BaseFloat> Iterator for HaltonJitter<S> {
+    type Item = Vector2<S>;
+
+    fn next(&mut self) -> Option<Vector2<S>> {
+        // The sequence starts at 1, as the first item of any Halton sequence
+        // is zero.
+        self.index = self.index % self.period + 1;
+        let half: S = cast(0.5f64).unwrap();
+        Some(Vector2::new(
+            halton::<S>(self.index, 2) - half,
+            halton::<S>(self.index, 3) - half,
+        ))
+    }
+}
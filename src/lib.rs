@@ -76,21 +76,32 @@ pub use num::*;
 pub use structure::*;
 
 pub use matrix::{Matrix2, Matrix3, Matrix4};
+pub use matrix::{Matrix2x3, Matrix2x4, Matrix3x2, Matrix3x4, Matrix4x2, Matrix4x3};
+#[cfg(feature = "const-generics")]
+pub use matrix_n::MatrixNxM;
 pub use quaternion::Quaternion;
 pub use vector::{dot, vec1, vec2, vec3, vec4, Vector1, Vector2, Vector3, Vector4};
+#[cfg(feature = "const-generics")]
+pub use vector_n::VectorN;
 
 pub use angle::{Deg, Rad};
 pub use euler::Euler;
 pub use point::{point1, point2, point3, Point1, Point2, Point3};
 pub use rotation::*;
 pub use transform::*;
+pub use unit::Unit;
 
 pub use projection::*;
+pub use viewport::Viewport;
+pub use viewport::{logical_to_physical, physical_to_logical, snap_to_pixel, snap_to_pixel_center};
 
 // Modules
 
+pub mod camera;
 pub mod conv;
+pub mod packing;
 pub mod prelude;
+pub mod space;
 
 mod macros;
 
@@ -98,12 +109,16 @@ mod num;
 mod structure;
 
 mod matrix;
+#[cfg(feature = "const-generics")]
+mod matrix_n;
 mod quaternion;
 
 #[cfg(feature = "simd")]
 mod quaternion_simd;
 
 mod vector;
+#[cfg(feature = "const-generics")]
+mod vector_n;
 
 #[cfg(feature = "simd")]
 mod vector_simd;
@@ -113,5 +128,7 @@ mod euler;
 mod point;
 mod rotation;
 mod transform;
+mod unit;
 
 mod projection;
+mod viewport;
@@ -17,13 +17,18 @@
 
 #![macro_use]
 
+// Attributes are passed inside the macro call, as `default_fn!(#[inline] ...)`,
+// because attributes on the call itself are ignored.
+
 #[cfg(feature = "simd")]
 macro_rules! default_fn {
+    { #[$attr:meta] $($tt:tt)* } => { #[$attr] default fn $( $tt )* };
     { $($tt:tt)* } => { default fn $( $tt )* };
 }
 
 #[cfg(not(feature = "simd"))]
 macro_rules! default_fn {
+    { #[$attr:meta] $($tt:tt)* } => { #[$attr] fn $( $tt )* };
     { $($tt:tt)* } => { fn $( $tt )* };
 }
 
@@ -35,16 +40,14 @@ macro_rules! impl_operator {
     }) => {
         impl<$S: $Constraint> $Op for $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!($op(self) -> $Output {
+            default_fn!(#[inline] $op(self) -> $Output {
                 let $x = self; $body
             });
         }
 
         impl<'a, $S: $Constraint> $Op for &'a $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!($op(self) -> $Output {
+            default_fn!(#[inline] $op(self) -> $Output {
                 let $x = self; $body
             });
         }
@@ -55,16 +58,14 @@ macro_rules! impl_operator {
     }) => {
         impl<$S: $Constraint> $Op<$Rhs> for $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!($op(self, other: $Rhs) -> $Output {
+            default_fn!(#[inline] $op(self, other: $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
             });
         }
 
         impl<'a, $S: $Constraint> $Op<$Rhs> for &'a $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!($op(self, other: $Rhs) -> $Output {
+            default_fn!(#[inline] $op(self, other: $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
             });
         }
@@ -75,32 +76,96 @@ macro_rules! impl_operator {
     }) => {
         impl<$S: $Constraint> $Op<$Rhs> for $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!( $op(self, other: $Rhs) -> $Output {
+            default_fn!(#[inline] $op(self, other: $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
             });
         }
 
         impl<'a, $S: $Constraint> $Op<&'a $Rhs> for $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!( $op(self, other: &'a $Rhs) -> $Output {
+            default_fn!(#[inline] $op(self, other: &'a $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
             });
         }
 
         impl<'a, $S: $Constraint> $Op<$Rhs> for &'a $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!( $op(self, other: $Rhs) -> $Output {
+            default_fn!(#[inline] $op(self, other: $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
             });
         }
 
         impl<'a, 'b, $S: $Constraint> $Op<&'a $Rhs> for &'b $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!( $op(self, other: &'a $Rhs) -> $Output {
+            default_fn!(#[inline] $op(self, other: &'a $Rhs) -> $Output {
+                let ($lhs, $rhs) = (self, other); $body
+            });
+        }
+    };
+    // When it is an unary operator on a const-generic type
+    (<$S:ident: $Constraint:ident $(, const $N:ident: usize)+> $Op:ident for $Lhs:ty {
+        fn $op:ident($x:ident) -> $Output:ty { $body:expr }
+    }) => {
+        impl<$S: $Constraint $(, const $N: usize)+> $Op for $Lhs {
+            type Output = $Output;
+            default_fn!(#[inline] $op(self) -> $Output {
+                let $x = self; $body
+            });
+        }
+
+        impl<'a, $S: $Constraint $(, const $N: usize)+> $Op for &'a $Lhs {
+            type Output = $Output;
+            default_fn!(#[inline] $op(self) -> $Output {
+                let $x = self; $body
+            });
+        }
+    };
+    // When the right operand is a scalar, on a const-generic type
+    (<$S:ident: $Constraint:ident $(, const $N:ident: usize)+> $Op:ident<$Rhs:ident> for $Lhs:ty {
+        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
+    }) => {
+        impl<$S: $Constraint $(, const $N: usize)+> $Op<$Rhs> for $Lhs {
+            type Output = $Output;
+            default_fn!(#[inline] $op(self, other: $Rhs) -> $Output {
+                let ($lhs, $rhs) = (self, other); $body
+            });
+        }
+
+        impl<'a, $S: $Constraint $(, const $N: usize)+> $Op<$Rhs> for &'a $Lhs {
+            type Output = $Output;
+            default_fn!(#[inline] $op(self, other: $Rhs) -> $Output {
+                let ($lhs, $rhs) = (self, other); $body
+            });
+        }
+    };
+    // When the right operand is a compound type, on a const-generic type
+    (<$S:ident: $Constraint:ident $(, const $N:ident: usize)+> $Op:ident<$Rhs:ty> for $Lhs:ty {
+        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
+    }) => {
+        impl<$S: $Constraint $(, const $N: usize)+> $Op<$Rhs> for $Lhs {
+            type Output = $Output;
+            default_fn!(#[inline] $op(self, other: $Rhs) -> $Output {
+                let ($lhs, $rhs) = (self, other); $body
+            });
+        }
+
+        impl<'a, $S: $Constraint $(, const $N: usize)+> $Op<&'a $Rhs> for $Lhs {
+            type Output = $Output;
+            default_fn!(#[inline] $op(self, other: &'a $Rhs) -> $Output {
+                let ($lhs, $rhs) = (self, other); $body
+            });
+        }
+
+        impl<'a, $S: $Constraint $(, const $N: usize)+> $Op<$Rhs> for &'a $Lhs {
+            type Output = $Output;
+            default_fn!(#[inline] $op(self, other: $Rhs) -> $Output {
+                let ($lhs, $rhs) = (self, other); $body
+            });
+        }
+
+        impl<'a, 'b, $S: $Constraint $(, const $N: usize)+> $Op<&'a $Rhs> for &'b $Lhs {
+            type Output = $Output;
+            default_fn!(#[inline] $op(self, other: &'a $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
             });
         }
@@ -111,16 +176,14 @@ macro_rules! impl_operator {
     }) => {
         impl $Op<$Rhs<$S>> for $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!( $op(self, other: $Rhs<$S>) -> $Output {
+            default_fn!(#[inline] $op(self, other: $Rhs<$S>) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
             });
         }
 
         impl<'a> $Op<&'a $Rhs<$S>> for $Lhs {
             type Output = $Output;
-            #[inline]
-            default_fn!( $op(self, other: &'a $Rhs<$S>) -> $Output {
+            default_fn!(#[inline] $op(self, other: &'a $Rhs<$S>) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
             });
         }
@@ -132,8 +195,14 @@ macro_rules! impl_assignment_operator {
         fn $op:ident(&mut $lhs:ident, $rhs:ident) $body:block
     }) => {
         impl<$S: $Constraint + $Op<$S>> $Op<$Rhs> for $Lhs {
-            #[inline]
-            default_fn!( $op(&mut $lhs, $rhs: $Rhs) $body );
+            default_fn!(#[inline] $op(&mut $lhs, $rhs: $Rhs) $body );
+        }
+    };
+    (<$S:ident: $Constraint:ident $(, const $N:ident: usize)+> $Op:ident<$Rhs:ty> for $Lhs:ty {
+        fn $op:ident(&mut $lhs:ident, $rhs:ident) $body:block
+    }) => {
+        impl<$S: $Constraint + $Op<$S> $(, const $N: usize)+> $Op<$Rhs> for $Lhs {
+            default_fn!(#[inline] $op(&mut $lhs, $rhs: $Rhs) $body );
         }
     };
 }
@@ -34,6 +34,7 @@ use num::BaseFloat;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
 use transform::{Transform, Transform2, Transform3};
+use unit::Unit;
 use vector::{Vector2, Vector3, Vector4};
 
 #[cfg(feature = "mint")]
@@ -84,6 +85,96 @@ pub struct Matrix4<S> {
     pub w: Vector4<S>,
 }
 
+/// A 2 x 3, column major matrix with 2 columns and 3 rows
+///
+/// This is equivalent to GLSL's `mat2x3`, and is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix2x3<S> {
+    /// The first column of the matrix.
+    pub x: Vector3<S>,
+    /// The second column of the matrix.
+    pub y: Vector3<S>,
+}
+
+/// A 3 x 2, column major matrix with 3 columns and 2 rows
+///
+/// This is equivalent to GLSL's `mat3x2`, and is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix3x2<S> {
+    /// The first column of the matrix.
+    pub x: Vector2<S>,
+    /// The second column of the matrix.
+    pub y: Vector2<S>,
+    /// The third column of the matrix.
+    pub z: Vector2<S>,
+}
+
+/// A 2 x 4, column major matrix with 2 columns and 4 rows
+///
+/// This is equivalent to GLSL's `mat2x4`, and is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix2x4<S> {
+    /// The first column of the matrix.
+    pub x: Vector4<S>,
+    /// The second column of the matrix.
+    pub y: Vector4<S>,
+}
+
+/// A 4 x 2, column major matrix with 4 columns and 2 rows
+///
+/// This is equivalent to GLSL's `mat4x2`, and is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4x2<S> {
+    /// The first column of the matrix.
+    pub x: Vector2<S>,
+    /// The second column of the matrix.
+    pub y: Vector2<S>,
+    /// The third column of the matrix.
+    pub z: Vector2<S>,
+    /// The fourth column of the matrix.
+    pub w: Vector2<S>,
+}
+
+/// A 3 x 4, column major matrix with 3 columns and 4 rows
+///
+/// This is equivalent to GLSL's `mat3x4`, and is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix3x4<S> {
+    /// The first column of the matrix.
+    pub x: Vector4<S>,
+    /// The second column of the matrix.
+    pub y: Vector4<S>,
+    /// The third column of the matrix.
+    pub z: Vector4<S>,
+}
+
+/// A 4 x 3, column major matrix with 4 columns and 3 rows
+///
+/// This is equivalent to GLSL's `mat4x3`, and is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4x3<S> {
+    /// The first column of the matrix.
+    pub x: Vector3<S>,
+    /// The second column of the matrix.
+    pub y: Vector3<S>,
+    /// The third column of the matrix.
+    pub z: Vector3<S>,
+    /// The fourth column of the matrix.
+    pub w: Vector3<S>,
+}
+
 impl<S> Matrix2<S> {
     /// Create a new matrix, providing values for each index.
     #[inline]
@@ -135,6 +226,7 @@ impl<S> Matrix3<S> {
     /// Create a new matrix, providing values for each index.
     #[inline]
     #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         c0r0:S, c0r1:S, c0r2:S,
         c1r0:S, c1r1:S, c1r2:S,
@@ -189,6 +281,9 @@ impl<S: BaseFloat> Matrix3<S> {
 
     /// Create a rotation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
+    ///
+    /// This rotates `dir` onto the positive `z` axis, so it is the rotation of
+    /// a left-handed view matrix, the same as `look_to_lh`.
     pub fn look_at(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
         let dir = dir.normalize();
         let side = up.cross(dir).normalize();
@@ -197,6 +292,32 @@ impl<S: BaseFloat> Matrix3<S> {
         Matrix3::from_cols(side, up, dir).transpose()
     }
 
+    /// Create the rotation of a right-handed view matrix, for a camera
+    /// looking in the direction `dir`, using `up` for orientation. This
+    /// rotates `dir` onto the negative `z` axis.
+    pub fn look_to_rh(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
+        Matrix3::look_at(-dir, up)
+    }
+
+    /// Create the rotation of a left-handed view matrix, for a camera
+    /// looking in the direction `dir`, using `up` for orientation. This
+    /// rotates `dir` onto the positive `z` axis.
+    pub fn look_to_lh(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
+        Matrix3::look_at(dir, up)
+    }
+
+    /// Create the rotation of a right-handed view matrix, for a camera at
+    /// `eye` looking at `center`, using `up` for orientation.
+    pub fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix3<S> {
+        Matrix3::look_to_rh(center - eye, up)
+    }
+
+    /// Create the rotation of a left-handed view matrix, for a camera at
+    /// `eye` looking at `center`, using `up` for orientation.
+    pub fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix3<S> {
+        Matrix3::look_to_lh(center - eye, up)
+    }
+
     /// Create a rotation matrix from a rotation around the `x` axis (pitch).
     pub fn from_angle_x<A: Into<Rad<S>>>(theta: A) -> Matrix3<S> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
@@ -237,9 +358,7 @@ impl<S: BaseFloat> Matrix3<S> {
     }
 
     /// Create a rotation matrix from an angle around an arbitrary axis.
-    ///
-    /// The specified axis **must be normalized**, or it represents an invalid rotation.
-    pub fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> Matrix3<S> {
+    pub fn from_axis_angle<A: Into<Rad<S>>>(axis: Unit<Vector3<S>>, angle: A) -> Matrix3<S> {
         let (s, c) = Rad::sin_cos(angle.into());
         let _1subc = S::one() - c;
 
@@ -259,6 +378,127 @@ impl<S: BaseFloat> Matrix3<S> {
         )
     }
 
+    /// The cofactor matrix, where each element is the signed determinant of
+    /// its minor. This is the inverse transpose scaled by the determinant, but
+    /// unlike the inverse it is also defined for singular matrices.
+    pub fn cofactor(&self) -> Matrix3<S> {
+        Matrix3::from_cols(self.y.cross(self.z), self.z.cross(self.x), self.x.cross(self.y))
+    }
+
+    /// The adjugate matrix, which is the transpose of the cofactor matrix. This
+    /// is the inverse scaled by the determinant, but unlike the inverse it is
+    /// also defined for singular matrices.
+    pub fn adjugate(&self) -> Matrix3<S> {
+        self.cofactor().transpose()
+    }
+
+    /// The matrix for transforming the normals of a surface transformed by
+    /// this matrix. This is the inverse transpose up to a positive scale
+    /// factor, and is computed from the cofactor matrix so that it is still
+    /// correct for singular matrices.
+    ///
+    /// The transformed normals have to be normalized again.
+    pub fn normal_matrix(&self) -> Matrix3<S> {
+        let cofactor = self.cofactor();
+        if self.determinant() < S::zero() {
+            -cofactor
+        } else {
+            cofactor
+        }
+    }
+
+    /// Apply this matrix to a 2D point as a projective transform, such as a
+    /// homography, including the divide by `w`.
+    ///
+    /// Returns `None` if the point is mapped to infinity, where `w` is zero.
+    /// Unlike `Transform::transform_point`, this does not assume that the
+    /// matrix is affine.
+    #[inline]
+    pub fn project_point(&self, point: Point2<S>) -> Option<Point2<S>> {
+        Point2::from_homogeneous(self * point.to_homogeneous())
+    }
+
+    /// Create the skew-symmetric matrix of `v`, such that `Matrix3::hat(v) * u`
+    /// is equal to `v.cross(u)`.
+    pub fn hat(v: Vector3<S>) -> Matrix3<S> {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix3::new(
+            S::zero(), v.z, -v.y,
+            -v.z, S::zero(), v.x,
+            v.y, -v.x, S::zero(),
+        )
+    }
+
+    /// The vector of a skew-symmetric matrix, which is the inverse of `Matrix3::hat`.
+    ///
+    /// Only the skew-symmetric part of the matrix is taken into account.
+    pub fn vee(&self) -> Vector3<S> {
+        let half: S = cast(0.5f64).unwrap();
+        Vector3::new(
+            (self.y.z - self.z.y) * half,
+            (self.z.x - self.x.z) * half,
+            (self.x.y - self.y.x) * half,
+        )
+    }
+
+    /// The matrix exponential of a skew-symmetric matrix, computed using
+    /// Rodrigues' formula.
+    fn exp_skew_symmetric(&self) -> Matrix3<S> {
+        let v = self.vee();
+        let theta = v.magnitude();
+        if theta.is_zero() {
+            return Matrix3::identity();
+        }
+
+        // Use `1 - cos(θ) = 2 sin²(θ / 2)` to avoid cancellation for small angles
+        let half: S = cast(0.5f64).unwrap();
+        let half_sinc = (theta * half).sin() / (theta * half);
+        let k = Matrix3::hat(v);
+        Matrix3::identity() + k * (theta.sin() / theta) + k * k * (half_sinc * half_sinc * half)
+    }
+
+    /// The matrix logarithm of a rotation matrix, with the angle of rotation
+    /// in the range `[0, π]`.
+    fn log_rotation(&self) -> Matrix3<S> {
+        // The skew-symmetric part is `sin(θ)` times the axis
+        let v = self.vee();
+        let sin = v.magnitude();
+        let cos = (self.trace() - S::one()) / (S::one() + S::one());
+        let theta = sin.atan2(cos);
+
+        if cos > cast(-0.9f64).unwrap() {
+            if sin.is_zero() {
+                return Matrix3::zero();
+            }
+            return Matrix3::hat(v * (theta / sin));
+        }
+
+        // Close to a half turn the skew-symmetric part vanishes, so recover the
+        // axis from the symmetric part `(1 - cos(θ)) * axis * axisᵀ` instead.
+        let half: S = cast(0.5f64).unwrap();
+        let symmetric = (*self + self.transpose()) * half - Matrix3::from_value(cos);
+        let diagonal = symmetric.diagonal();
+        let i = if diagonal.x > diagonal.y && diagonal.x > diagonal.z {
+            0
+        } else if diagonal.y > diagonal.z {
+            1
+        } else {
+            2
+        };
+        let mut axis = symmetric[i].normalize();
+        if axis.dot(v) < S::zero() {
+            axis = -axis;
+        }
+        Matrix3::hat(axis * theta)
+    }
+
+    /// Is this a rotation matrix, up to rounding errors.
+    fn is_rotation(&self) -> bool {
+        let tolerance = S::default_epsilon() * cast(16).unwrap();
+        norm_inf(self.transpose() * self - Matrix3::identity()) <= tolerance
+            && self.determinant() > S::zero()
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
@@ -269,6 +509,7 @@ impl<S> Matrix4<S> {
     /// Create a new matrix, providing values for each index.
     #[inline]
     #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         c0r0: S, c0r1: S, c0r2: S, c0r3: S,
         c1r0: S, c1r1: S, c1r2: S, c1r3: S,
@@ -349,10 +590,52 @@ impl<S: BaseFloat> Matrix4<S> {
 
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `center`, using `up` for orientation.
+    ///
+    /// This is a right-handed view matrix, the same as `look_at_rh`.
     pub fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
         Matrix4::look_at_dir(eye, center - eye, up)
     }
 
+    /// Create a right-handed view matrix, for a camera at `eye` looking in
+    /// the direction `dir`, using `up` for orientation. The camera looks down
+    /// its negative `z` axis.
+    ///
+    /// This is the same as `look_at_dir`.
+    pub fn look_to_rh(eye: Point3<S>, dir: Vector3<S>, up: Vector3<S>) -> Matrix4<S> {
+        Matrix4::look_at_dir(eye, dir, up)
+    }
+
+    /// Create a left-handed view matrix, for a camera at `eye` looking in the
+    /// direction `dir`, using `up` for orientation. The camera looks down its
+    /// positive `z` axis.
+    pub fn look_to_lh(eye: Point3<S>, dir: Vector3<S>, up: Vector3<S>) -> Matrix4<S> {
+        let f = dir.normalize();
+        let s = up.cross(f).normalize();
+        let u = f.cross(s);
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            s.x, u.x, f.x, S::zero(),
+            s.y, u.y, f.y, S::zero(),
+            s.z, u.z, f.z, S::zero(),
+            -eye.dot(s), -eye.dot(u), -eye.dot(f), S::one(),
+        )
+    }
+
+    /// Create a right-handed view matrix, for a camera at `eye` looking at
+    /// `center`, using `up` for orientation.
+    ///
+    /// This is the same as `look_at`.
+    pub fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+        Matrix4::look_to_rh(eye, center - eye, up)
+    }
+
+    /// Create a left-handed view matrix, for a camera at `eye` looking at
+    /// `center`, using `up` for orientation.
+    pub fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+        Matrix4::look_to_lh(eye, center - eye, up)
+    }
+
     /// Create a homogeneous transformation matrix from a rotation around the `x` axis (pitch).
     pub fn from_angle_x<A: Into<Rad<S>>>(theta: A) -> Matrix4<S> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
@@ -396,9 +679,7 @@ impl<S: BaseFloat> Matrix4<S> {
     }
 
     /// Create a homogeneous transformation matrix from an angle around an arbitrary axis.
-    ///
-    /// The specified axis **must be normalized**, or it represents an invalid rotation.
-    pub fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> Matrix4<S> {
+    pub fn from_axis_angle<A: Into<Rad<S>>>(axis: Unit<Vector3<S>>, angle: A) -> Matrix4<S> {
         let (s, c) = Rad::sin_cos(angle.into());
         let _1subc = S::one() - c;
 
@@ -423,12 +704,243 @@ impl<S: BaseFloat> Matrix4<S> {
         )
     }
 
+    /// The upper-left 3 x 3 part of the matrix, which is the linear part of
+    /// an affine transformation.
+    pub fn upper_left_3x3(&self) -> Matrix3<S> {
+        Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate())
+    }
+
+    /// The cofactor matrix, where each element is the signed determinant of
+    /// its minor. This is the inverse transpose scaled by the determinant, but
+    /// unlike the inverse it is also defined for singular matrices.
+    pub fn cofactor(&self) -> Matrix4<S> {
+        self.adjugate().transpose()
+    }
+
+    /// The adjugate matrix, which is the transpose of the cofactor matrix. This
+    /// is the inverse scaled by the determinant, but unlike the inverse it is
+    /// also defined for singular matrices.
+    pub fn adjugate(&self) -> Matrix4<S> {
+        let t = self.transpose();
+        let cf = |i, j| {
+            let mat = match i {
+                0 => Matrix3::from_cols(t.y.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j)),
+                1 => Matrix3::from_cols(t.x.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j)),
+                2 => Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.w.truncate_n(j)),
+                3 => Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.z.truncate_n(j)),
+                _ => panic!("out of range"),
+            };
+            let sign = if (i + j) & 1 == 1 {
+                -S::one()
+            } else {
+                S::one()
+            };
+            mat.determinant() * sign
+        };
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
+            cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
+            cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
+            cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3),
+        )
+    }
+
+    /// The matrix for transforming the normals of a surface transformed by
+    /// this matrix, which is the normal matrix of its upper-left 3 x 3 part.
+    /// See `Matrix3::normal_matrix`.
+    pub fn normal_matrix(&self) -> Matrix3<S> {
+        self.upper_left_3x3().normal_matrix()
+    }
+
+    /// Apply this matrix to a point as a projective transform, such as a
+    /// perspective projection, including the divide by `w`.
+    ///
+    /// Returns `None` if the point is mapped to infinity, where `w` is zero,
+    /// for example a point on the plane of the eye of a perspective
+    /// projection.
+    #[inline]
+    pub fn project_point(&self, point: Point3<S>) -> Option<Point3<S>> {
+        Point3::from_homogeneous(self * point.to_homogeneous())
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.w.is_finite() && self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
     }
 }
 
+impl<S> Matrix2x3<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub const fn new(
+        c0r0: S, c0r1: S, c0r2: S,
+        c1r0: S, c1r1: S, c1r2: S,
+    ) -> Matrix2x3<S> {
+        Matrix2x3::from_cols(
+            Vector3::new(c0r0, c0r1, c0r2),
+            Vector3::new(c1r0, c1r1, c1r2),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(c0: Vector3<S>, c1: Vector3<S>) -> Matrix2x3<S> {
+        Matrix2x3 { x: c0, y: c1 }
+    }
+}
+
+impl<S> Matrix3x2<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub const fn new(
+        c0r0: S, c0r1: S,
+        c1r0: S, c1r1: S,
+        c2r0: S, c2r1: S,
+    ) -> Matrix3x2<S> {
+        Matrix3x2::from_cols(
+            Vector2::new(c0r0, c0r1),
+            Vector2::new(c1r0, c1r1),
+            Vector2::new(c2r0, c2r1),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(c0: Vector2<S>, c1: Vector2<S>, c2: Vector2<S>) -> Matrix3x2<S> {
+        Matrix3x2 {
+            x: c0,
+            y: c1,
+            z: c2,
+        }
+    }
+}
+
+impl<S> Matrix2x4<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        c0r0: S, c0r1: S, c0r2: S, c0r3: S,
+        c1r0: S, c1r1: S, c1r2: S, c1r3: S,
+    ) -> Matrix2x4<S> {
+        Matrix2x4::from_cols(
+            Vector4::new(c0r0, c0r1, c0r2, c0r3),
+            Vector4::new(c1r0, c1r1, c1r2, c1r3),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(c0: Vector4<S>, c1: Vector4<S>) -> Matrix2x4<S> {
+        Matrix2x4 { x: c0, y: c1 }
+    }
+}
+
+impl<S> Matrix4x2<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        c0r0: S, c0r1: S,
+        c1r0: S, c1r1: S,
+        c2r0: S, c2r1: S,
+        c3r0: S, c3r1: S,
+    ) -> Matrix4x2<S> {
+        Matrix4x2::from_cols(
+            Vector2::new(c0r0, c0r1),
+            Vector2::new(c1r0, c1r1),
+            Vector2::new(c2r0, c2r1),
+            Vector2::new(c3r0, c3r1),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(
+        c0: Vector2<S>,
+        c1: Vector2<S>,
+        c2: Vector2<S>,
+        c3: Vector2<S>,
+    ) -> Matrix4x2<S> {
+        Matrix4x2 {
+            x: c0,
+            y: c1,
+            z: c2,
+            w: c3,
+        }
+    }
+}
+
+impl<S> Matrix3x4<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        c0r0: S, c0r1: S, c0r2: S, c0r3: S,
+        c1r0: S, c1r1: S, c1r2: S, c1r3: S,
+        c2r0: S, c2r1: S, c2r2: S, c2r3: S,
+    ) -> Matrix3x4<S> {
+        Matrix3x4::from_cols(
+            Vector4::new(c0r0, c0r1, c0r2, c0r3),
+            Vector4::new(c1r0, c1r1, c1r2, c1r3),
+            Vector4::new(c2r0, c2r1, c2r2, c2r3),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(c0: Vector4<S>, c1: Vector4<S>, c2: Vector4<S>) -> Matrix3x4<S> {
+        Matrix3x4 {
+            x: c0,
+            y: c1,
+            z: c2,
+        }
+    }
+}
+
+impl<S> Matrix4x3<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        c0r0: S, c0r1: S, c0r2: S,
+        c1r0: S, c1r1: S, c1r2: S,
+        c2r0: S, c2r1: S, c2r2: S,
+        c3r0: S, c3r1: S, c3r2: S,
+    ) -> Matrix4x3<S> {
+        Matrix4x3::from_cols(
+            Vector3::new(c0r0, c0r1, c0r2),
+            Vector3::new(c1r0, c1r1, c1r2),
+            Vector3::new(c2r0, c2r1, c2r2),
+            Vector3::new(c3r0, c3r1, c3r2),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(
+        c0: Vector3<S>,
+        c1: Vector3<S>,
+        c2: Vector3<S>,
+        c3: Vector3<S>,
+    ) -> Matrix4x3<S> {
+        Matrix4x3 {
+            x: c0,
+            y: c1,
+            z: c2,
+            w: c3,
+        }
+    }
+}
+
 impl<S: BaseFloat> Zero for Matrix2<S> {
     #[inline]
     fn zero() -> Matrix2<S> {
@@ -609,6 +1121,14 @@ impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
     fn is_symmetric(&self) -> bool {
         ulps_eq!(self[0][1], &self[1][0]) && ulps_eq!(self[1][0], &self[0][1])
     }
+
+    fn exp(&self) -> Matrix2<S> {
+        exp_pade(*self)
+    }
+
+    fn log(&self) -> Option<Matrix2<S>> {
+        log_inverse_scaling_squaring(*self)
+    }
 }
 
 impl<S: BaseFloat> Matrix for Matrix3<S> {
@@ -696,14 +1216,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
         if det == S::zero() {
             None
         } else {
-            Some(
-                Matrix3::from_cols(
-                    self[1].cross(self[2]) / det,
-                    self[2].cross(self[0]) / det,
-                    self[0].cross(self[1]) / det,
-                )
-                .transpose(),
-            )
+            Some(self.adjugate() / det)
         }
     }
 
@@ -724,6 +1237,30 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
             && ulps_eq!(self[2][0], &self[0][2])
             && ulps_eq!(self[2][1], &self[1][2])
     }
+
+    /// The matrix exponential. Skew-symmetric matrices, such as those
+    /// created by `Matrix3::hat`, are exponentiated using Rodrigues' formula,
+    /// giving the rotation of `self.vee().magnitude()` radians around
+    /// `self.vee()`.
+    fn exp(&self) -> Matrix3<S> {
+        if (*self + self.transpose()).is_zero() {
+            self.exp_skew_symmetric()
+        } else {
+            exp_pade(*self)
+        }
+    }
+
+    /// The principal matrix logarithm. The logarithm of a rotation matrix is
+    /// the skew-symmetric matrix of the axis of rotation, scaled by the angle
+    /// of rotation in the range `[0, π]`. This includes half turns, which
+    /// have two logarithms, of which either one is returned.
+    fn log(&self) -> Option<Matrix3<S>> {
+        if self.is_rotation() {
+            Some(self.log_rotation())
+        } else {
+            log_inverse_scaling_squaring(*self)
+        }
+    }
 }
 
 impl<S: BaseFloat> Matrix for Matrix4<S> {
@@ -822,39 +1359,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
         if det == S::zero() {
             None
         } else {
-            let inv_det = S::one() / det;
-            let t = self.transpose();
-            let cf = |i, j| {
-                let mat = match i {
-                    0 => {
-                        Matrix3::from_cols(t.y.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    1 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    2 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    3 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.z.truncate_n(j))
-                    }
-                    _ => panic!("out of range"),
-                };
-                let sign = if (i + j) & 1 == 1 {
-                    -S::one()
-                } else {
-                    S::one()
-                };
-                mat.determinant() * sign * inv_det
-            };
-
-            #[cfg_attr(rustfmt, rustfmt_skip)]
-            Some(Matrix4::new(
-                cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
-                cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
-                cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
-                cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3),
-            ))
+            Some(self.adjugate() * (S::one() / det))
         }
     }
     #[cfg(feature = "simd")]
@@ -903,8 +1408,139 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
             && ulps_eq!(self[3][1], &self[1][3])
             && ulps_eq!(self[3][2], &self[2][3])
     }
+
+    fn exp(&self) -> Matrix4<S> {
+        exp_pade(*self)
+    }
+
+    fn log(&self) -> Option<Matrix4<S>> {
+        log_inverse_scaling_squaring(*self)
+    }
 }
 
+macro_rules! impl_rect_matrix {
+    ($MatrixCxR:ident, $Transpose:ident, $Column:ident, $Row:ident,
+     { $($field:ident : $col_index:expr),+ }, { $($row_index:expr),+ }) => {
+        impl<S: BaseFloat> $MatrixCxR<S> {
+            /// Are all entries in the matrix finite.
+            pub fn is_finite(&self) -> bool {
+                $(self.$field.is_finite())&&+
+            }
+        }
+
+        impl<S: BaseFloat> Zero for $MatrixCxR<S> {
+            #[inline]
+            fn zero() -> $MatrixCxR<S> {
+                $MatrixCxR { $($field: $Column::zero()),+ }
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                ulps_eq!(self, &Self::zero())
+            }
+        }
+
+        impl<S: BaseFloat> VectorSpace for $MatrixCxR<S> {
+            type Scalar = S;
+        }
+
+        impl<S: BaseFloat> Matrix for $MatrixCxR<S> {
+            type Column = $Column<S>;
+            type Row = $Row<S>;
+            type Transpose = $Transpose<S>;
+
+            #[inline]
+            fn row(&self, r: usize) -> $Row<S> {
+                $Row::new($(self[$col_index][r]),+)
+            }
+
+            #[inline]
+            fn swap_rows(&mut self, a: usize, b: usize) {
+                $(self[$col_index].swap_elements(a, b);)+
+            }
+
+            #[inline]
+            fn swap_columns(&mut self, a: usize, b: usize) {
+                unsafe { ptr::swap(&mut self[a], &mut self[b]) };
+            }
+
+            #[inline]
+            fn swap_elements(&mut self, a: (usize, usize), b: (usize, usize)) {
+                let (ac, ar) = a;
+                let (bc, br) = b;
+                unsafe { ptr::swap(&mut self[ac][ar], &mut self[bc][br]) };
+            }
+
+            fn transpose(&self) -> $Transpose<S> {
+                $Transpose::from_cols($(self.row($row_index)),+)
+            }
+        }
+
+        impl<S: BaseFloat> approx::AbsDiffEq for $MatrixCxR<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                cast(1.0e-6f64).unwrap()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $($Column::abs_diff_eq(&self.$field, &other.$field, epsilon))&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $MatrixCxR<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $($Column::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $MatrixCxR<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $($Column::ulps_eq(&self.$field, &other.$field, epsilon, max_ulps))&&+
+            }
+        }
+
+        impl_operator!(<S: BaseFloat> Mul<$Row<S> > for $MatrixCxR<S> {
+            fn mul(matrix, vector) -> $Column<S> {
+                $Column::zero() $(+ matrix.$field * vector[$col_index])+
+            }
+        });
+
+        #[cfg(feature = "rand")]
+        impl<S> Distribution<$MatrixCxR<S>> for Standard
+        where
+            Standard: Distribution<$Column<S>>,
+            S: BaseFloat,
+        {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $MatrixCxR<S> {
+                $MatrixCxR { $($field: rng.gen()),+ }
+            }
+        }
+    }
+}
+
+impl_rect_matrix!(Matrix2x3, Matrix3x2, Vector3, Vector2, { x: 0, y: 1 }, { 0, 1, 2 });
+impl_rect_matrix!(Matrix3x2, Matrix2x3, Vector2, Vector3, { x: 0, y: 1, z: 2 }, { 0, 1 });
+impl_rect_matrix!(Matrix2x4, Matrix4x2, Vector4, Vector2, { x: 0, y: 1 }, { 0, 1, 2, 3 });
+impl_rect_matrix!(Matrix4x2, Matrix2x4, Vector2, Vector4, { x: 0, y: 1, z: 2, w: 3 }, { 0, 1 });
+impl_rect_matrix!(Matrix3x4, Matrix4x3, Vector4, Vector3, { x: 0, y: 1, z: 2 }, { 0, 1, 2, 3 });
+impl_rect_matrix!(Matrix4x3, Matrix3x4, Vector3, Vector4, { x: 0, y: 1, z: 2, w: 3 }, { 0, 1, 2 });
+
 impl<S: BaseFloat> approx::AbsDiffEq for Matrix2<S> {
     type Epsilon = S::Epsilon;
 
@@ -1105,7 +1741,8 @@ impl<S: BaseFloat> Transform<Point3<S>> for Matrix4<S> {
     }
 
     fn transform_point(&self, point: Point3<S>) -> Point3<S> {
-        Point3::from_homogeneous(self * point.to_homogeneous())
+        let v = self * point.to_homogeneous();
+        Point3::from_vec(v.truncate() * (S::one() / v.w))
     }
 
     fn concat(&self, other: &Matrix4<S>) -> Matrix4<S> {
@@ -1175,20 +1812,6 @@ macro_rules! impl_matrix {
             }
         }
 
-        impl<S: BaseFloat> iter::Product for $MatrixN<S> {
-            #[inline]
-            fn product<I: Iterator<Item=$MatrixN<S>>>(iter: I) -> $MatrixN<S> {
-                iter.fold($MatrixN::identity(), Mul::mul)
-            }
-        }
-
-        impl<'a, S: 'a + BaseFloat> iter::Product<&'a $MatrixN<S>> for $MatrixN<S> {
-            #[inline]
-            fn product<I: Iterator<Item=&'a $MatrixN<S>>>(iter: I) -> $MatrixN<S> {
-                iter.fold($MatrixN::identity(), Mul::mul)
-            }
-        }
-
         impl_scalar_ops!($MatrixN<usize> { $($field),+ });
         impl_scalar_ops!($MatrixN<u8> { $($field),+ });
         impl_scalar_ops!($MatrixN<u16> { $($field),+ });
@@ -1233,10 +1856,40 @@ macro_rules! impl_scalar_ops {
     };
 }
 
+macro_rules! impl_matrix_product {
+    ($MatrixN:ident) => {
+        impl<S: BaseFloat> iter::Product for $MatrixN<S> {
+            #[inline]
+            fn product<I: Iterator<Item=$MatrixN<S>>>(iter: I) -> $MatrixN<S> {
+                iter.fold($MatrixN::identity(), Mul::mul)
+            }
+        }
+
+        impl<'a, S: 'a + BaseFloat> iter::Product<&'a $MatrixN<S>> for $MatrixN<S> {
+            #[inline]
+            fn product<I: Iterator<Item=&'a $MatrixN<S>>>(iter: I) -> $MatrixN<S> {
+                iter.fold($MatrixN::identity(), Mul::mul)
+            }
+        }
+    }
+}
+
 impl_matrix!(Matrix2, Vector2 { x: 0, y: 1 });
 impl_matrix!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
 #[cfg_attr(rustfmt, rustfmt_skip)]
 impl_matrix!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
+impl_matrix!(Matrix2x3, Vector3 { x: 0, y: 1 });
+impl_matrix!(Matrix3x2, Vector2 { x: 0, y: 1, z: 2 });
+impl_matrix!(Matrix2x4, Vector4 { x: 0, y: 1 });
+#[cfg_attr(rustfmt, rustfmt_skip)]
+impl_matrix!(Matrix4x2, Vector2 { x: 0, y: 1, z: 2, w: 3 });
+impl_matrix!(Matrix3x4, Vector4 { x: 0, y: 1, z: 2 });
+#[cfg_attr(rustfmt, rustfmt_skip)]
+impl_matrix!(Matrix4x3, Vector3 { x: 0, y: 1, z: 2, w: 3 });
+
+impl_matrix_product!(Matrix2);
+impl_matrix_product!(Matrix3);
+impl_matrix_product!(Matrix4);
 
 macro_rules! impl_mv_operator {
     ($MatrixN:ident, $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
@@ -1299,14 +1952,49 @@ impl_operator!(<S: BaseFloat> Mul<Matrix4<S> > for Matrix4<S> {
     }
 });
 
+// Products between the matrices whose shapes are compatible, where the number of
+// columns of the left operand matches the number of rows of the right operand.
+macro_rules! impl_matrix_mul {
+    ($Lhs:ident * $Rhs:ident => $Output:ident { $($field:ident),+ }) => {
+        impl_operator!(<S: BaseFloat> Mul<$Rhs<S> > for $Lhs<S> {
+            fn mul(lhs, rhs) -> $Output<S> { $Output::from_cols($(lhs * rhs.$field),+) }
+        });
+    }
+}
+
+impl_matrix_mul!(Matrix2 * Matrix3x2 => Matrix3x2 { x, y, z });
+impl_matrix_mul!(Matrix2 * Matrix4x2 => Matrix4x2 { x, y, z, w });
+impl_matrix_mul!(Matrix3x2 * Matrix2x3 => Matrix2 { x, y });
+impl_matrix_mul!(Matrix3x2 * Matrix3 => Matrix3x2 { x, y, z });
+impl_matrix_mul!(Matrix3x2 * Matrix4x3 => Matrix4x2 { x, y, z, w });
+impl_matrix_mul!(Matrix4x2 * Matrix2x4 => Matrix2 { x, y });
+impl_matrix_mul!(Matrix4x2 * Matrix3x4 => Matrix3x2 { x, y, z });
+impl_matrix_mul!(Matrix4x2 * Matrix4 => Matrix4x2 { x, y, z, w });
+impl_matrix_mul!(Matrix2x3 * Matrix2 => Matrix2x3 { x, y });
+impl_matrix_mul!(Matrix2x3 * Matrix3x2 => Matrix3 { x, y, z });
+impl_matrix_mul!(Matrix2x3 * Matrix4x2 => Matrix4x3 { x, y, z, w });
+impl_matrix_mul!(Matrix3 * Matrix2x3 => Matrix2x3 { x, y });
+impl_matrix_mul!(Matrix3 * Matrix4x3 => Matrix4x3 { x, y, z, w });
+impl_matrix_mul!(Matrix4x3 * Matrix2x4 => Matrix2x3 { x, y });
+impl_matrix_mul!(Matrix4x3 * Matrix3x4 => Matrix3 { x, y, z });
+impl_matrix_mul!(Matrix4x3 * Matrix4 => Matrix4x3 { x, y, z, w });
+impl_matrix_mul!(Matrix2x4 * Matrix2 => Matrix2x4 { x, y });
+impl_matrix_mul!(Matrix2x4 * Matrix3x2 => Matrix3x4 { x, y, z });
+impl_matrix_mul!(Matrix2x4 * Matrix4x2 => Matrix4 { x, y, z, w });
+impl_matrix_mul!(Matrix3x4 * Matrix2x3 => Matrix2x4 { x, y });
+impl_matrix_mul!(Matrix3x4 * Matrix3 => Matrix3x4 { x, y, z });
+impl_matrix_mul!(Matrix3x4 * Matrix4x3 => Matrix4 { x, y, z, w });
+impl_matrix_mul!(Matrix4 * Matrix2x4 => Matrix2x4 { x, y });
+impl_matrix_mul!(Matrix4 * Matrix3x4 => Matrix3x4 { x, y, z });
+
 macro_rules! index_operators {
-    ($MatrixN:ident<$S:ident>, $n:expr, $Output:ty, $I:ty) => {
+    ($MatrixN:ident<$S:ident>, $cols:expr, $rows:expr, $Output:ty, $I:ty) => {
         impl<$S> Index<$I> for $MatrixN<$S> {
             type Output = $Output;
 
             #[inline]
             fn index<'a>(&'a self, i: $I) -> &'a $Output {
-                let v: &[[$S; $n]; $n] = self.as_ref();
+                let v: &[[$S; $rows]; $cols] = self.as_ref();
                 From::from(&v[i])
             }
         }
@@ -1314,28 +2002,34 @@ macro_rules! index_operators {
         impl<$S> IndexMut<$I> for $MatrixN<$S> {
             #[inline]
             fn index_mut<'a>(&'a mut self, i: $I) -> &'a mut $Output {
-                let v: &mut [[$S; $n]; $n] = self.as_mut();
+                let v: &mut [[$S; $rows]; $cols] = self.as_mut();
                 From::from(&mut v[i])
             }
         }
     };
 }
 
-index_operators!(Matrix2<S>, 2, Vector2<S>, usize);
-index_operators!(Matrix3<S>, 3, Vector3<S>, usize);
-index_operators!(Matrix4<S>, 4, Vector4<S>, usize);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], Range<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], Range<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], Range<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeTo<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeTo<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeTo<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeFrom<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeFrom<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeFrom<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeFull);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeFull);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeFull);
+index_operators!(Matrix2<S>, 2, 2, Vector2<S>, usize);
+index_operators!(Matrix3<S>, 3, 3, Vector3<S>, usize);
+index_operators!(Matrix4<S>, 4, 4, Vector4<S>, usize);
+index_operators!(Matrix2x3<S>, 2, 3, Vector3<S>, usize);
+index_operators!(Matrix3x2<S>, 3, 2, Vector2<S>, usize);
+index_operators!(Matrix2x4<S>, 2, 4, Vector4<S>, usize);
+index_operators!(Matrix4x2<S>, 4, 2, Vector2<S>, usize);
+index_operators!(Matrix3x4<S>, 3, 4, Vector4<S>, usize);
+index_operators!(Matrix4x3<S>, 4, 3, Vector3<S>, usize);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], Range<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], Range<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], Range<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeTo<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeTo<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeTo<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeFrom<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeFrom<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeFrom<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeFull);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeFull);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeFull);
 
 impl<A> From<Euler<A>> for Matrix3<A::Unitless>
 where
@@ -1377,99 +2071,105 @@ where
 }
 
 macro_rules! fixed_array_conversions {
-    ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $n:expr) => {
-        impl<$S> Into<[[$S; $n]; $n]> for $MatrixN<$S> {
+    ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $cols:expr, $rows:expr) => {
+        impl<$S> Into<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn into(self) -> [[$S; $n]; $n] {
+            fn into(self) -> [[$S; $rows]; $cols] {
                 match self { $MatrixN { $($field),+ } => [$($field.into()),+] }
             }
         }
 
-        impl<$S> AsRef<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S> AsRef<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn as_ref(&self) -> &[[$S; $n]; $n] {
+            fn as_ref(&self) -> &[[$S; $rows]; $cols] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S> AsMut<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S> AsMut<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn as_mut(&mut self) -> &mut [[$S; $n]; $n] {
+            fn as_mut(&mut self) -> &mut [[$S; $rows]; $cols] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S: Copy> From<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S: Copy> From<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn from(m: [[$S; $n]; $n]) -> $MatrixN<$S> {
+            fn from(m: [[$S; $rows]; $cols]) -> $MatrixN<$S> {
                 // We need to use a copy here because we can't pattern match on arrays yet
                 $MatrixN { $($field: From::from(m[$index])),+ }
             }
         }
 
-        impl<'a, $S> From<&'a [[$S; $n]; $n]> for &'a $MatrixN<$S> {
+        impl<'a, $S> From<&'a [[$S; $rows]; $cols]> for &'a $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a [[$S; $n]; $n]) -> &'a $MatrixN<$S> {
+            fn from(m: &'a [[$S; $rows]; $cols]) -> &'a $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        impl<'a, $S> From<&'a mut [[$S; $n]; $n]> for &'a mut $MatrixN<$S> {
+        impl<'a, $S> From<&'a mut [[$S; $rows]; $cols]> for &'a mut $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a mut [[$S; $n]; $n]) -> &'a mut $MatrixN<$S> {
+            fn from(m: &'a mut [[$S; $rows]; $cols]) -> &'a mut $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        // impl<$S> Into<[$S; ($n * $n)]> for $MatrixN<$S> {
+        // impl<$S> Into<[$S; ($cols * $rows)]> for $MatrixN<$S> {
         //     #[inline]
-        //     fn into(self) -> [[$S; $n]; $n] {
+        //     fn into(self) -> [[$S; $rows]; $cols] {
         //         // TODO: Not sure how to implement this...
         //         unimplemented!()
         //     }
         // }
 
-        impl<$S> AsRef<[$S; ($n * $n)]> for $MatrixN<$S> {
+        impl<$S> AsRef<[$S; ($cols * $rows)]> for $MatrixN<$S> {
             #[inline]
-            fn as_ref(&self) -> &[$S; ($n * $n)] {
+            fn as_ref(&self) -> &[$S; ($cols * $rows)] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S> AsMut<[$S; ($n * $n)]> for $MatrixN<$S> {
+        impl<$S> AsMut<[$S; ($cols * $rows)]> for $MatrixN<$S> {
             #[inline]
-            fn as_mut(&mut self) -> &mut [$S; ($n * $n)] {
+            fn as_mut(&mut self) -> &mut [$S; ($cols * $rows)] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        // impl<$S> From<[$S; ($n * $n)]> for $MatrixN<$S> {
+        // impl<$S> From<[$S; ($cols * $rows)]> for $MatrixN<$S> {
         //     #[inline]
-        //     fn from(m: [$S; ($n * $n)]) -> $MatrixN<$S> {
+        //     fn from(m: [$S; ($cols * $rows)]) -> $MatrixN<$S> {
         //         // TODO: Not sure how to implement this...
         //         unimplemented!()
         //     }
         // }
 
-        impl<'a, $S> From<&'a [$S; ($n * $n)]> for &'a $MatrixN<$S> {
+        impl<'a, $S> From<&'a [$S; ($cols * $rows)]> for &'a $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a [$S; ($n * $n)]) -> &'a $MatrixN<$S> {
+            fn from(m: &'a [$S; ($cols * $rows)]) -> &'a $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        impl<'a, $S> From<&'a mut [$S; ($n * $n)]> for &'a mut $MatrixN<$S> {
+        impl<'a, $S> From<&'a mut [$S; ($cols * $rows)]> for &'a mut $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a mut [$S; ($n * $n)]) -> &'a mut $MatrixN<$S> {
+            fn from(m: &'a mut [$S; ($cols * $rows)]) -> &'a mut $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
     }
 }
 
-fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2);
-fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3);
-fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4);
+fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2, 2);
+fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3, 3);
+fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4, 4);
+fixed_array_conversions!(Matrix2x3<S> { x:0, y:1 }, 2, 3);
+fixed_array_conversions!(Matrix3x2<S> { x:0, y:1, z:2 }, 3, 2);
+fixed_array_conversions!(Matrix2x4<S> { x:0, y:1 }, 2, 4);
+fixed_array_conversions!(Matrix4x2<S> { x:0, y:1, z:2, w:3 }, 4, 2);
+fixed_array_conversions!(Matrix3x4<S> { x:0, y:1, z:2 }, 3, 4);
+fixed_array_conversions!(Matrix4x3<S> { x:0, y:1, z:2, w:3 }, 4, 3);
 
 #[cfg(feature = "mint")]
 macro_rules! mint_conversions {
@@ -1497,6 +2197,19 @@ mint_conversions!(Matrix2 { x, y }, ColumnMatrix2);
 mint_conversions!(Matrix3 { x, y, z }, ColumnMatrix3);
 #[cfg(feature = "mint")]
 mint_conversions!(Matrix4 { x, y, z, w }, ColumnMatrix4);
+// `mint` names its matrices as rows x columns
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix2x3 { x, y }, ColumnMatrix3x2);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix3x2 { x, y, z }, ColumnMatrix2x3);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix2x4 { x, y }, ColumnMatrix4x2);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix4x2 { x, y, z, w }, ColumnMatrix2x4);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix3x4 { x, y, z }, ColumnMatrix4x3);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix4x3 { x, y, z, w }, ColumnMatrix3x4);
 
 impl<S: BaseFloat> From<Matrix2<S>> for Matrix3<S> {
     /// Clone the elements of a 2-dimensional matrix into the top-left corner
@@ -1603,6 +2316,48 @@ impl<S: fmt::Debug> fmt::Debug for Matrix4<S> {
     }
 }
 
+impl<S: fmt::Debug> fmt::Debug for Matrix2x3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix2x3 ")?;
+        <[[S; 3]; 2] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix3x2<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix3x2 ")?;
+        <[[S; 2]; 3] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix2x4<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix2x4 ")?;
+        <[[S; 4]; 2] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix4x2<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix4x2 ")?;
+        <[[S; 2]; 4] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix3x4<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix3x4 ")?;
+        <[[S; 4]; 3] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix4x3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix4x3 ")?;
+        <[[S; 3]; 4] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<S> Distribution<Matrix2<S>> for Standard
 where
@@ -1651,6 +2406,116 @@ where
     }
 }
 
+/// The matrix exponential, computed using a degree 6 Padé approximant with
+/// scaling and squaring.
+fn exp_pade<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: M) -> M {
+    if !is_finite(m) {
+        return m * S::nan();
+    }
+
+    // Scale the matrix down so that the approximant is accurate
+    let half: S = cast(0.5f64).unwrap();
+    let mut a = m;
+    let mut squarings = 0;
+    while norm_inf(a) > half {
+        a = a * half;
+        squarings += 1;
+    }
+
+    const Q: usize = 6;
+    let identity = M::identity();
+    let mut c = half;
+    let mut x = a;
+    let mut numerator = identity + a * c;
+    let mut denominator = identity - a * c;
+    for k in 2..(Q + 1) {
+        c = c * cast(Q - k + 1).unwrap() / cast(k * (2 * Q - k + 1)).unwrap();
+        x = a * x;
+        numerator = numerator + x * c;
+        if k % 2 == 0 {
+            denominator = denominator + x * c;
+        } else {
+            denominator = denominator - x * c;
+        }
+    }
+
+    let mut result = denominator.invert().unwrap() * numerator;
+    for _ in 0..squarings {
+        result = result * result;
+    }
+    result
+}
+
+/// The principal matrix logarithm, computed using inverse scaling and
+/// squaring.
+fn log_inverse_scaling_squaring<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: M) -> Option<M> {
+    if !is_finite(m) {
+        return None;
+    }
+
+    // Take square roots until the matrix is close to the identity
+    let identity = M::identity();
+    let mut x = m;
+    let mut roots = 0;
+    while norm_inf(x - identity) > cast(0.25f64).unwrap() {
+        if roots == 64 {
+            return None;
+        }
+        x = principal_sqrt(x)?;
+        roots += 1;
+    }
+
+    // log(X) = 2 atanh(Z) where Z = (X - I)(X + I)⁻¹
+    let z = (x - identity) * (x + identity).invert()?;
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = z;
+    for i in 1..12 {
+        term = term * z2;
+        sum = sum + term / cast(2 * i + 1).unwrap();
+    }
+
+    let two = S::one() + S::one();
+    Some(sum * two.powi(roots + 1))
+}
+
+/// The principal square root, computed using the Denman–Beavers iteration.
+fn principal_sqrt<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: M) -> Option<M> {
+    let half: S = cast(0.5f64).unwrap();
+    let mut y = m;
+    let mut z = M::identity();
+    let mut last_step = S::infinity();
+    for _ in 0..64 {
+        let next_y = (y + z.invert()?) * half;
+        z = (z + y.invert()?) * half;
+        let step = norm_inf(next_y - y);
+        y = next_y;
+
+        // Stop once converged, or once rounding errors stop the step from shrinking
+        let scale = norm_inf(y);
+        if step <= S::epsilon() * scale
+            || (step >= last_step && step <= S::epsilon().sqrt() * scale)
+        {
+            return Some(y);
+        }
+        last_step = step;
+    }
+    None
+}
+
+/// The maximum absolute row sum of the matrix.
+fn norm_inf<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: M) -> S {
+    let n = M::ColumnRow::len();
+    (0..n)
+        .map(|i| (0..n).fold(S::zero(), |sum, j| sum + m[j][i].abs()))
+        .fold(S::zero(), |a, b| a.max(b))
+}
+
+/// Are all entries in the matrix finite.
+fn is_finite<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: M) -> bool {
+    (0..M::ColumnRow::len()).all(|j| m[j].is_finite())
+}
+
 // Sub procedure for SIMD when dealing with determinant and inversion
 #[inline]
 unsafe fn det_sub_proc_unsafe<S: BaseFloat>(
@@ -0,0 +1,380 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::cast;
+use std::array;
+use std::fmt;
+use std::iter;
+use std::mem;
+use std::ops::*;
+use std::ptr;
+
+use structure::*;
+
+use approx;
+use matrix::{Matrix2, Matrix3, Matrix4};
+use matrix::{Matrix2x3, Matrix2x4, Matrix3x2, Matrix3x4, Matrix4x2, Matrix4x3};
+use num::BaseFloat;
+use vector_n::VectorN;
+
+/// A column major matrix with `R` rows and `C` columns, backed by an array
+/// of `VectorN` columns.
+///
+/// This is the const-generic counterpart of the fixed-size matrix types. Note
+/// that the dimensions are given as rows then columns, so `MatrixNxM<S, 3, 2>`
+/// has the same layout as `Matrix2x3<S>`, which has two columns of three rows.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+pub struct MatrixNxM<S, const R: usize, const C: usize> {
+    /// The columns of the matrix.
+    pub cols: [VectorN<S, R>; C],
+}
+
+impl<S, const R: usize, const C: usize> MatrixNxM<S, R, C> {
+    /// Create a new matrix, providing the columns.
+    #[inline]
+    pub const fn from_cols(cols: [VectorN<S, R>; C]) -> MatrixNxM<S, R, C> {
+        MatrixNxM { cols }
+    }
+
+    /// Create a new matrix, calling `f` with the row and column index of
+    /// each element.
+    #[inline]
+    pub fn from_fn<F>(mut f: F) -> MatrixNxM<S, R, C>
+        where F: FnMut(usize, usize) -> S
+    {
+        MatrixNxM { cols: array::from_fn(|c| VectorN::from_fn(|r| f(r, c))) }
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> MatrixNxM<S, R, C> {
+    /// Are all entries in the matrix finite.
+    pub fn is_finite(&self) -> bool {
+        self.cols.iter().all(|c| c.is_finite())
+    }
+}
+
+impl<S: BaseFloat, const N: usize> MatrixNxM<S, N, N> {
+    /// Create an identity matrix.
+    #[inline]
+    pub fn identity() -> MatrixNxM<S, N, N> {
+        MatrixNxM::from_fn(|r, c| if r == c { S::one() } else { S::zero() })
+    }
+
+    /// Create a matrix with the given values along the diagonal.
+    #[inline]
+    pub fn from_diagonal(diagonal: VectorN<S, N>) -> MatrixNxM<S, N, N> {
+        MatrixNxM::from_fn(|r, c| if r == c { diagonal[r] } else { S::zero() })
+    }
+
+    /// The diagonal of the matrix.
+    #[inline]
+    pub fn diagonal(&self) -> VectorN<S, N> {
+        VectorN::from_fn(|i| self.cols[i][i])
+    }
+
+    /// The sum of the main diagonal of the matrix.
+    #[inline]
+    pub fn trace(&self) -> S {
+        (0..N).fold(S::zero(), |acc, i| acc + self.cols[i][i])
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> Zero for MatrixNxM<S, R, C> {
+    #[inline]
+    fn zero() -> MatrixNxM<S, R, C> {
+        MatrixNxM { cols: [VectorN::zero(); C] }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        ulps_eq!(self, &Self::zero())
+    }
+}
+
+impl<S: BaseFloat, const N: usize> One for MatrixNxM<S, N, N> {
+    #[inline]
+    fn one() -> MatrixNxM<S, N, N> {
+        MatrixNxM::identity()
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> iter::Sum<MatrixNxM<S, R, C>>
+    for MatrixNxM<S, R, C>
+{
+    #[inline]
+    fn sum<I: Iterator<Item = MatrixNxM<S, R, C>>>(iter: I) -> MatrixNxM<S, R, C> {
+        iter.fold(MatrixNxM::zero(), Add::add)
+    }
+}
+
+impl<'a, S: 'a + BaseFloat, const R: usize, const C: usize> iter::Sum<&'a MatrixNxM<S, R, C>>
+    for MatrixNxM<S, R, C>
+{
+    #[inline]
+    fn sum<I: Iterator<Item = &'a MatrixNxM<S, R, C>>>(iter: I) -> MatrixNxM<S, R, C> {
+        iter.fold(MatrixNxM::zero(), Add::add)
+    }
+}
+
+impl<S: BaseFloat, const N: usize> iter::Product<MatrixNxM<S, N, N>> for MatrixNxM<S, N, N> {
+    #[inline]
+    fn product<I: Iterator<Item = MatrixNxM<S, N, N>>>(iter: I) -> MatrixNxM<S, N, N> {
+        iter.fold(MatrixNxM::identity(), Mul::mul)
+    }
+}
+
+impl<'a, S: 'a + BaseFloat, const N: usize> iter::Product<&'a MatrixNxM<S, N, N>>
+    for MatrixNxM<S, N, N>
+{
+    #[inline]
+    fn product<I: Iterator<Item = &'a MatrixNxM<S, N, N>>>(iter: I) -> MatrixNxM<S, N, N> {
+        iter.fold(MatrixNxM::identity(), Mul::mul)
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> VectorSpace for MatrixNxM<S, R, C> {
+    type Scalar = S;
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> Matrix for MatrixNxM<S, R, C> {
+    type Row = VectorN<S, C>;
+    type Column = VectorN<S, R>;
+    type Transpose = MatrixNxM<S, C, R>;
+
+    #[inline]
+    fn row(&self, r: usize) -> VectorN<S, C> {
+        VectorN::from_fn(|c| self.cols[c][r])
+    }
+
+    #[inline]
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in self.cols.iter_mut() {
+            col.swap_elements(a, b);
+        }
+    }
+
+    #[inline]
+    fn swap_columns(&mut self, a: usize, b: usize) {
+        self.cols.swap(a, b);
+    }
+
+    #[inline]
+    fn swap_elements(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let (ac, ar) = a;
+        let (bc, br) = b;
+        unsafe { ptr::swap(&mut self[ac][ar], &mut self[bc][br]) };
+    }
+
+    fn transpose(&self) -> MatrixNxM<S, C, R> {
+        MatrixNxM::from_fn(|r, c| self.cols[r][c])
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> approx::AbsDiffEq for MatrixNxM<S, R, C> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        cast(1.0e-6f64).unwrap()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        self.cols.iter().zip(other.cols.iter())
+            .all(|(a, b)| VectorN::abs_diff_eq(a, b, epsilon))
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> approx::RelativeEq for MatrixNxM<S, R, C> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        self.cols.iter().zip(other.cols.iter())
+            .all(|(a, b)| VectorN::relative_eq(a, b, epsilon, max_relative))
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> approx::UlpsEq for MatrixNxM<S, R, C> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        self.cols.iter().zip(other.cols.iter())
+            .all(|(a, b)| VectorN::ulps_eq(a, b, epsilon, max_ulps))
+    }
+}
+
+impl_operator!(<S: BaseFloat, const R: usize, const C: usize> Neg for MatrixNxM<S, R, C> {
+    fn neg(matrix) -> MatrixNxM<S, R, C> { MatrixNxM::from_fn(|r, c| -matrix.cols[c][r]) }
+});
+
+impl_operator!(<S: BaseFloat, const R: usize, const C: usize> Mul<S> for MatrixNxM<S, R, C> {
+    fn mul(matrix, scalar) -> MatrixNxM<S, R, C> {
+        MatrixNxM::from_fn(|r, c| matrix.cols[c][r] * scalar)
+    }
+});
+impl_operator!(<S: BaseFloat, const R: usize, const C: usize> Div<S> for MatrixNxM<S, R, C> {
+    fn div(matrix, scalar) -> MatrixNxM<S, R, C> {
+        MatrixNxM::from_fn(|r, c| matrix.cols[c][r] / scalar)
+    }
+});
+impl_operator!(<S: BaseFloat, const R: usize, const C: usize> Rem<S> for MatrixNxM<S, R, C> {
+    fn rem(matrix, scalar) -> MatrixNxM<S, R, C> {
+        MatrixNxM::from_fn(|r, c| matrix.cols[c][r] % scalar)
+    }
+});
+impl_assignment_operator!(<S: BaseFloat, const R: usize, const C: usize> MulAssign<S> for MatrixNxM<S, R, C> {
+    fn mul_assign(&mut self, scalar) { for col in self.cols.iter_mut() { *col *= scalar } }
+});
+impl_assignment_operator!(<S: BaseFloat, const R: usize, const C: usize> DivAssign<S> for MatrixNxM<S, R, C> {
+    fn div_assign(&mut self, scalar) { for col in self.cols.iter_mut() { *col /= scalar } }
+});
+impl_assignment_operator!(<S: BaseFloat, const R: usize, const C: usize> RemAssign<S> for MatrixNxM<S, R, C> {
+    fn rem_assign(&mut self, scalar) { for col in self.cols.iter_mut() { *col %= scalar } }
+});
+
+impl_operator!(<S: BaseFloat, const R: usize, const C: usize> Add<MatrixNxM<S, R, C> > for MatrixNxM<S, R, C> {
+    fn add(lhs, rhs) -> MatrixNxM<S, R, C> {
+        MatrixNxM::from_fn(|r, c| lhs.cols[c][r] + rhs.cols[c][r])
+    }
+});
+impl_operator!(<S: BaseFloat, const R: usize, const C: usize> Sub<MatrixNxM<S, R, C> > for MatrixNxM<S, R, C> {
+    fn sub(lhs, rhs) -> MatrixNxM<S, R, C> {
+        MatrixNxM::from_fn(|r, c| lhs.cols[c][r] - rhs.cols[c][r])
+    }
+});
+impl_assignment_operator!(<S: BaseFloat, const R: usize, const C: usize> AddAssign<MatrixNxM<S, R, C> > for MatrixNxM<S, R, C> {
+    fn add_assign(&mut self, other) { for c in 0..C { self.cols[c] += other.cols[c] } }
+});
+impl_assignment_operator!(<S: BaseFloat, const R: usize, const C: usize> SubAssign<MatrixNxM<S, R, C> > for MatrixNxM<S, R, C> {
+    fn sub_assign(&mut self, other) { for c in 0..C { self.cols[c] -= other.cols[c] } }
+});
+
+impl_operator!(<S: BaseFloat, const R: usize, const C: usize> Mul<VectorN<S, C> > for MatrixNxM<S, R, C> {
+    fn mul(matrix, vector) -> VectorN<S, R> {
+        VectorN::from_fn(|r| (0..C).fold(S::zero(), |acc, c| acc + matrix.cols[c][r] * vector[c]))
+    }
+});
+
+impl_operator!(<S: BaseFloat, const R: usize, const K: usize, const C: usize> Mul<MatrixNxM<S, K, C> > for MatrixNxM<S, R, K> {
+    fn mul(lhs, rhs) -> MatrixNxM<S, R, C> {
+        MatrixNxM::from_fn(|r, c| (0..K).fold(S::zero(), |acc, k| acc + lhs.cols[k][r] * rhs.cols[c][k]))
+    }
+});
+
+impl<S, const R: usize, const C: usize> Index<usize> for MatrixNxM<S, R, C> {
+    type Output = VectorN<S, R>;
+
+    #[inline]
+    fn index(&self, i: usize) -> &VectorN<S, R> {
+        &self.cols[i]
+    }
+}
+
+impl<S, const R: usize, const C: usize> IndexMut<usize> for MatrixNxM<S, R, C> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut VectorN<S, R> {
+        &mut self.cols[i]
+    }
+}
+
+impl<S, const R: usize, const C: usize> From<[[S; R]; C]> for MatrixNxM<S, R, C> {
+    #[inline]
+    fn from(m: [[S; R]; C]) -> MatrixNxM<S, R, C> {
+        MatrixNxM { cols: m.map(VectorN::new) }
+    }
+}
+
+impl<S, const R: usize, const C: usize> Into<[[S; R]; C]> for MatrixNxM<S, R, C> {
+    #[inline]
+    fn into(self) -> [[S; R]; C] {
+        self.cols.map(|col| col.data)
+    }
+}
+
+impl<S, const R: usize, const C: usize> AsRef<[[S; R]; C]> for MatrixNxM<S, R, C> {
+    #[inline]
+    fn as_ref(&self) -> &[[S; R]; C] {
+        unsafe { &*(self as *const MatrixNxM<S, R, C> as *const [[S; R]; C]) }
+    }
+}
+
+impl<S, const R: usize, const C: usize> AsMut<[[S; R]; C]> for MatrixNxM<S, R, C> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [[S; R]; C] {
+        unsafe { &mut *(self as *mut MatrixNxM<S, R, C> as *mut [[S; R]; C]) }
+    }
+}
+
+impl<S: fmt::Debug, const R: usize, const C: usize> fmt::Debug for MatrixNxM<S, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MatrixNxM ")?;
+        <[[S; R]; C] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+macro_rules! impl_fixed_matrix_conversions {
+    ($MatrixN:ident, $cols:expr, $rows:expr) => {
+        impl<S: Copy> From<$MatrixN<S>> for MatrixNxM<S, $rows, $cols> {
+            #[inline]
+            fn from(m: $MatrixN<S>) -> MatrixNxM<S, $rows, $cols> {
+                let m: [[S; $rows]; $cols] = m.into();
+                MatrixNxM::from(m)
+            }
+        }
+
+        impl<S: Copy> From<MatrixNxM<S, $rows, $cols>> for $MatrixN<S> {
+            #[inline]
+            fn from(m: MatrixNxM<S, $rows, $cols>) -> $MatrixN<S> {
+                let m: [[S; $rows]; $cols] = m.into();
+                $MatrixN::from(m)
+            }
+        }
+
+        impl<S> AsRef<$MatrixN<S>> for MatrixNxM<S, $rows, $cols> {
+            #[inline]
+            fn as_ref(&self) -> &$MatrixN<S> {
+                unsafe { mem::transmute(self) }
+            }
+        }
+
+        impl<S> AsMut<$MatrixN<S>> for MatrixNxM<S, $rows, $cols> {
+            #[inline]
+            fn as_mut(&mut self) -> &mut $MatrixN<S> {
+                unsafe { mem::transmute(self) }
+            }
+        }
+    }
+}
+
+impl_fixed_matrix_conversions!(Matrix2, 2, 2);
+impl_fixed_matrix_conversions!(Matrix3, 3, 3);
+impl_fixed_matrix_conversions!(Matrix4, 4, 4);
+impl_fixed_matrix_conversions!(Matrix2x3, 2, 3);
+impl_fixed_matrix_conversions!(Matrix3x2, 3, 2);
+impl_fixed_matrix_conversions!(Matrix2x4, 2, 4);
+impl_fixed_matrix_conversions!(Matrix4x2, 4, 2);
+impl_fixed_matrix_conversions!(Matrix3x4, 3, 4);
+impl_fixed_matrix_conversions!(Matrix4x3, 4, 3);
@@ -18,7 +18,7 @@ use approx;
 use std::fmt;
 use std::ops::*;
 
-use num_traits::{Float, Num, NumCast};
+use num_traits::{Float, Num, NumCast, PrimInt, WrappingAdd, WrappingMul, WrappingSub};
 
 /// Base numeric types with partial ordering
 pub trait BaseNum:
@@ -51,6 +51,40 @@ impl<T> BaseNum for T where
 {
 }
 
+/// Base integer types
+///
+/// Vectors of these types support the bitwise operators, and shifts by a
+/// `usize` or by a vector of `usize` per component. Like for the primitive
+/// types, shifting by the number of bits in the type or more panics in debug
+/// builds.
+pub trait BaseInt:
+    BaseNum
+    + PrimInt
+    + WrappingAdd
+    + WrappingSub
+    + WrappingMul
+    + BitAndAssign
+    + BitOrAssign
+    + BitXorAssign
+    + ShlAssign<usize>
+    + ShrAssign<usize>
+{
+}
+
+impl<T> BaseInt for T where
+    T: BaseNum
+        + PrimInt
+        + WrappingAdd
+        + WrappingSub
+        + WrappingMul
+        + BitAndAssign
+        + BitOrAssign
+        + BitXorAssign
+        + ShlAssign<usize>
+        + ShrAssign<usize>
+{
+}
+
 /// Base floating point types
 pub trait BaseFloat:
     BaseNum
@@ -0,0 +1,424 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Packed vector formats for compressing vertex attributes, such as normals,
+//! texture coordinates and colors.
+//!
+//! Unit vectors can also be encoded as two components with the octahedral
+//! mapping, or as the index of the nearest point of a spherical Fibonacci
+//! point set.
+//!
+//! The conversions follow the rules of the Vulkan and OpenGL specifications.
+//! Normalized integers round to the nearest value, and floats round to the
+//! nearest value with ties to even. NaN converts to zero in the normalized
+//! formats.
+//!
+//! With the `const-generics` feature, the functions taking and returning
+//! `[_; N]` arrays work with any of `Vector2<f32>`, `Vector3<f32>` and
+//! `Vector4<f32>`, and with arrays of `f32`:
+//!
+//! ```rust
+//! # #[cfg(feature = "const-generics")]
+//! # fn main() {
+//! use cgmath::Vector3;
+//! use cgmath::packing::{pack_snorm8, unpack_snorm8};
+//!
+//! let normal = Vector3::new(0.0, -1.0, 0.5);
+//! let packed = pack_snorm8(normal);
+//! assert_eq!(packed, [0, -127, 64]);
+//! let unpacked: Vector3<f32> = unpack_snorm8(packed);
+//! assert_eq!(unpacked.y, -1.0);
+//! # }
+//! # #[cfg(not(feature = "const-generics"))]
+//! # fn main() {}
+//! ```
+
+use num_traits::{cast, Float};
+
+use structure::*;
+
+use angle::Rad;
+use num::BaseFloat;
+use vector::{Vector2, Vector3, Vector4};
+
+#[cfg(feature = "const-generics")]
+mod arrays;
+#[cfg(feature = "const-generics")]
+pub use self::arrays::*;
+
+/// Pack a vector into 10 bit unsigned normalized `x`, `y` and `z` components
+/// and a 2 bit unsigned normalized `w` component.
+///
+/// `x` is stored in the least significant bits, matching the
+/// `A2B10G10R10_UNORM_PACK32` format of Vulkan and the
+/// `UNSIGNED_INT_2_10_10_10_REV` type of OpenGL.
+pub fn pack_unorm10_10_10_2(v: Vector4<f32>) -> u32 {
+    pack_unorm(v.x, 10)
+        | pack_unorm(v.y, 10) << 10
+        | pack_unorm(v.z, 10) << 20
+        | pack_unorm(v.w, 2) << 30
+}
+
+/// Unpack a vector packed with `pack_unorm10_10_10_2`.
+pub fn unpack_unorm10_10_10_2(packed: u32) -> Vector4<f32> {
+    Vector4::new(
+        unpack_unorm(packed & 0x3ff, 10),
+        unpack_unorm(packed >> 10 & 0x3ff, 10),
+        unpack_unorm(packed >> 20 & 0x3ff, 10),
+        unpack_unorm(packed >> 30, 2),
+    )
+}
+
+/// Pack a vector into 10 bit signed normalized `x`, `y` and `z` components
+/// and a 2 bit signed normalized `w` component.
+///
+/// `x` is stored in the least significant bits, matching the
+/// `A2B10G10R10_SNORM_PACK32` format of Vulkan and the `INT_2_10_10_10_REV`
+/// type of OpenGL.
+pub fn pack_snorm10_10_10_2(v: Vector4<f32>) -> u32 {
+    (pack_snorm(v.x, 10) as u32 & 0x3ff)
+        | (pack_snorm(v.y, 10) as u32 & 0x3ff) << 10
+        | (pack_snorm(v.z, 10) as u32 & 0x3ff) << 20
+        | (pack_snorm(v.w, 2) as u32) << 30
+}
+
+/// Unpack a vector packed with `pack_snorm10_10_10_2`.
+pub fn unpack_snorm10_10_10_2(packed: u32) -> Vector4<f32> {
+    // Shift each field to the top and back, to extend its sign.
+    let packed = packed as i32;
+    Vector4::new(
+        unpack_snorm(packed << 22 >> 22, 10),
+        unpack_snorm(packed << 12 >> 22, 10),
+        unpack_snorm(packed << 2 >> 22, 10),
+        unpack_snorm(packed >> 30, 2),
+    )
+}
+
+/// Pack a vector into unsigned 11 bit floats for `x` and `y`, and an unsigned
+/// 10 bit float for `z`.
+///
+/// `x` is stored in the least significant bits, matching the
+/// `B10G11R11_UFLOAT_PACK32` format of Vulkan and the
+/// `UNSIGNED_INT_10F_11F_11F_REV` type of OpenGL. Negative values become
+/// zero, and finite values too large for the format become the largest
+/// finite value.
+pub fn pack_float11_11_10(v: Vector3<f32>) -> u32 {
+    pack_ufloat(v.x, 6) | pack_ufloat(v.y, 6) << 11 | pack_ufloat(v.z, 5) << 22
+}
+
+/// Unpack a vector packed with `pack_float11_11_10`, which is exact.
+pub fn unpack_float11_11_10(packed: u32) -> Vector3<f32> {
+    Vector3::new(
+        unpack_float(packed & 0x7ff, 6),
+        unpack_float(packed >> 11 & 0x7ff, 6),
+        unpack_float(packed >> 22, 5),
+    )
+}
+
+/// Map a unit vector to a point in `[-1, 1]²`, using the octahedral mapping.
+///
+/// The vector is projected onto an octahedron, whose lower half is folded
+/// over the upper half to fill the square. This distributes the precision of
+/// the encoding fairly evenly over the sphere.
+pub fn encode_octahedral<S: BaseFloat>(v: Vector3<S>) -> Vector2<S> {
+    let v = v / (v.x.abs() + v.y.abs() + v.z.abs());
+    if v.z < S::zero() {
+        octahedral_wrap(v.x, v.y)
+    } else {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+/// Map a point in `[-1, 1]²` back to a unit vector, using the octahedral
+/// mapping.
+pub fn decode_octahedral<S: BaseFloat>(e: Vector2<S>) -> Vector3<S> {
+    let z = S::one() - e.x.abs() - e.y.abs();
+    let xy = if z < S::zero() {
+        octahedral_wrap(e.x, e.y)
+    } else {
+        e
+    };
+    Vector3::new(xy.x, xy.y, z).normalize()
+}
+
+/// Pack a unit vector into two 8 bit signed normalized integers, using the
+/// octahedral mapping.
+///
+/// Of the four nearest quantized values, this picks the one that decodes
+/// closest to the vector, which is the precise variant of [Cigolle et al.].
+///
+/// [Cigolle et al.]: http://jcgt.org/published/0003/02/01/
+pub fn pack_octahedral_snorm8(v: Vector3<f32>) -> [i8; 2] {
+    let [x, y] = pack_octahedral(v, 8);
+    [x as i8, y as i8]
+}
+
+/// Unpack a unit vector packed with `pack_octahedral_snorm8`.
+pub fn unpack_octahedral_snorm8(packed: [i8; 2]) -> Vector3<f32> {
+    decode_octahedral(Vector2::new(
+        unpack_snorm(i32::from(packed[0]), 8),
+        unpack_snorm(i32::from(packed[1]), 8),
+    ))
+}
+
+/// Pack a unit vector into two 16 bit signed normalized integers, using the
+/// octahedral mapping.
+///
+/// Of the four nearest quantized values, this picks the one that decodes
+/// closest to the vector, which is the precise variant of [Cigolle et al.].
+///
+/// [Cigolle et al.]: http://jcgt.org/published/0003/02/01/
+pub fn pack_octahedral_snorm16(v: Vector3<f32>) -> [i16; 2] {
+    let [x, y] = pack_octahedral(v, 16);
+    [x as i16, y as i16]
+}
+
+/// Unpack a unit vector packed with `pack_octahedral_snorm16`.
+pub fn unpack_octahedral_snorm16(packed: [i16; 2]) -> Vector3<f32> {
+    decode_octahedral(Vector2::new(
+        unpack_snorm(i32::from(packed[0]), 16),
+        unpack_snorm(i32::from(packed[1]), 16),
+    ))
+}
+
+/// The point with the given `index` of a spherical Fibonacci point set with
+/// `count` points.
+///
+/// The points are spread evenly over the unit sphere, which makes them useful
+/// for sampling directions. The first point is near the positive z axis, and
+/// the last point near the negative z axis.
+///
+/// The angles are computed in double precision, so that large point sets are
+/// also accurate for `f32`.
+pub fn spherical_fibonacci<S: BaseFloat>(index: u32, count: u32) -> Vector3<S> {
+    assert!(
+        index < count,
+        "The index must be less than the number of points, found: index: {:?}, count: {:?}",
+        index,
+        count
+    );
+    let (i, n) = (f64::from(index), f64::from(count));
+    let phi = Rad::full_turn() * fract(i * (GOLDEN_RATIO - 1.0));
+    let cos_theta = 1.0 - (2.0 * i + 1.0) / n;
+    let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+    let (sin_phi, cos_phi) = Rad::sin_cos(phi);
+    Vector3::new(
+        cast(cos_phi * sin_theta).unwrap(),
+        cast(sin_phi * sin_theta).unwrap(),
+        cast(cos_theta).unwrap(),
+    )
+}
+
+/// The index of the point of a spherical Fibonacci point set with `count`
+/// points that is nearest to the unit vector `v`.
+///
+/// Together with `spherical_fibonacci`, this encodes unit vectors as a
+/// single integer, using the method of [Keinert et al.].
+///
+/// [Keinert et al.]: https://doi.org/10.1145/2816795.2818131
+pub fn inverse_spherical_fibonacci<S: BaseFloat>(v: Vector3<S>, count: u32) -> u32 {
+    assert!(count > 0, "The number of points cannot be zero");
+    let n = f64::from(count);
+    let p = Vector3::new(
+        cast::<S, f64>(v.x).unwrap(),
+        cast::<S, f64>(v.y).unwrap(),
+        cast::<S, f64>(v.z).unwrap(),
+    );
+    let two_pi = Rad::<f64>::full_turn().0;
+    let phi = p.y.atan2(p.x).min(two_pi / 2.0);
+    let cos_theta = clamp(p.z, -1.0, 1.0);
+
+    // Find the two Fibonacci numbers that span the local lattice of points
+    // around the vector, and the lattice cell containing it.
+    let k = ((n * two_pi / 2.0 * 5.0f64.sqrt() * (1.0 - cos_theta * cos_theta)).ln()
+        / (GOLDEN_RATIO * GOLDEN_RATIO).ln())
+    .floor()
+    .max(2.0);
+    let fk = GOLDEN_RATIO.powf(k) / 5.0f64.sqrt();
+    let (f0, f1) = (fk.round(), (fk * GOLDEN_RATIO).round());
+    let basis_phi =
+        |f: f64| two_pi * fract((f + 1.0) * (GOLDEN_RATIO - 1.0)) - two_pi * (GOLDEN_RATIO - 1.0);
+    let (a, b) = (basis_phi(f0), basis_phi(f1));
+    let (c, d) = (-2.0 * f0 / n, -2.0 * f1 / n);
+    let det = a * d - b * c;
+    let z = cos_theta - (1.0 - 1.0 / n);
+    let cell = Vector2::new(
+        ((d * phi - b * z) / det).floor(),
+        ((a * z - c * phi) / det).floor(),
+    );
+
+    // Pick the nearest point at the corners of the cell.
+    let mut nearest = (Float::infinity(), 0);
+    for &(u, w) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)].iter() {
+        let corner_z = c * (cell.x + u) + d * (cell.y + w) + (1.0 - 1.0 / n);
+        // Reflect the points beyond the poles back onto the sphere.
+        let corner_z = clamp(corner_z, -1.0, 1.0) * 2.0 - corner_z;
+        let index = clamp((n * 0.5 - corner_z * n * 0.5).floor(), 0.0, n - 1.0) as u32;
+        let distance = (spherical_fibonacci::<f64>(index, count) - p).magnitude2();
+        if distance < nearest.0 {
+            nearest = (distance, index);
+        }
+    }
+    nearest.1
+}
+
+/// The golden ratio, `(1 + √5) / 2`.
+const GOLDEN_RATIO: f64 = 1.618_033_988_749_895;
+
+fn fract(x: f64) -> f64 {
+    x - x.floor()
+}
+
+fn clamp<T: PartialOrd>(x: T, min: T, max: T) -> T {
+    if x < min {
+        min
+    } else if x > max {
+        max
+    } else {
+        x
+    }
+}
+
+/// Fold the lower half of the octahedron over the upper half, or back.
+fn octahedral_wrap<S: BaseFloat>(x: S, y: S) -> Vector2<S> {
+    let sign = |a: S| if a >= S::zero() { S::one() } else { -S::one() };
+    Vector2::new(
+        (S::one() - y.abs()) * sign(x),
+        (S::one() - x.abs()) * sign(y),
+    )
+}
+
+/// Quantize the octahedral mapping of a unit vector to signed normalized
+/// integers with `bits` bits, picking the nearest of the four candidates.
+fn pack_octahedral(v: Vector3<f32>, bits: u32) -> [i32; 2] {
+    let max = (1i32 << (bits - 1)) - 1;
+    let e = encode_octahedral(v) * max as f32;
+    let (x, y) = (e.x.floor() as i32, e.y.floor() as i32);
+
+    // Compare the distances rather than the cosines, which lose too much
+    // precision for nearby vectors.
+    let mut best = ([0, 0], Float::infinity());
+    for &(dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)].iter() {
+        let candidate = [clamp(x + dx, -max, max), clamp(y + dy, -max, max)];
+        let decoded = decode_octahedral(Vector2::new(
+            unpack_snorm(candidate[0], bits),
+            unpack_snorm(candidate[1], bits),
+        ));
+        let distance = (decoded - v).magnitude2();
+        if distance < best.1 {
+            best = (candidate, distance);
+        }
+    }
+    best.0
+}
+
+/// Convert to an unsigned normalized integer with `bits` bits.
+fn pack_unorm(value: f32, bits: u32) -> u32 {
+    if value.is_nan() {
+        return 0;
+    }
+    let max = ((1u32 << bits) - 1) as f32;
+    (clamp(value, 0.0, 1.0) * max).round() as u32
+}
+
+fn unpack_unorm(value: u32, bits: u32) -> f32 {
+    value as f32 / ((1u32 << bits) - 1) as f32
+}
+
+/// Convert to a signed normalized integer with `bits` bits.
+fn pack_snorm(value: f32, bits: u32) -> i32 {
+    if value.is_nan() {
+        return 0;
+    }
+    let max = ((1i32 << (bits - 1)) - 1) as f32;
+    (clamp(value, -1.0, 1.0) * max).round() as i32
+}
+
+fn unpack_snorm(value: i32, bits: u32) -> f32 {
+    let max = ((1i32 << (bits - 1)) - 1) as f32;
+    (value as f32 / max).max(-1.0)
+}
+
+/// Convert to an unsigned float with a 5 bit exponent.
+fn pack_ufloat(value: f32, mantissa_bits: u32) -> u32 {
+    if value.is_nan() {
+        pack_small_float(value.to_bits() & 0x7fff_ffff, mantissa_bits, true)
+    } else if value.is_sign_negative() {
+        0
+    } else {
+        pack_small_float(value.to_bits(), mantissa_bits, true)
+    }
+}
+
+/// Convert the bits of a non-negative `f32` to a float with a 5 bit exponent
+/// and `mantissa_bits` bits of mantissa, rounding to nearest with ties to even.
+fn pack_small_float(bits: u32, mantissa_bits: u32, saturate: bool) -> u32 {
+    let infinity = 0x1f << mantissa_bits;
+    let max_finite = infinity - 1;
+    let dropped_bits = 23 - mantissa_bits;
+
+    if bits >= 0x7f80_0000 {
+        if bits == 0x7f80_0000 {
+            return infinity;
+        }
+        // Keep the payload of NaN where it fits, and make sure it stays quiet.
+        return infinity | (bits & 0x7f_ffff) >> dropped_bits | 1 << (mantissa_bits - 1);
+    }
+
+    // The exponent with the bias of the smaller float, which is 15.
+    let exponent = (bits >> 23) as i32 - 127 + 15;
+    let packed = if exponent > 0 {
+        // Rounding may carry into the exponent, which is the right result.
+        round_shift((exponent as u32) << 23 | (bits & 0x7f_ffff), dropped_bits)
+    } else {
+        // Subnormal, with the implicit leading one made explicit.
+        let shift = (1 - exponent) as u32 + dropped_bits;
+        if shift > 24 {
+            return 0;
+        }
+        round_shift(bits & 0x7f_ffff | 0x80_0000, shift)
+    };
+
+    if packed >= infinity && saturate {
+        max_finite
+    } else {
+        packed.min(infinity)
+    }
+}
+
+/// Shift right, rounding to nearest with ties to even.
+fn round_shift(value: u32, shift: u32) -> u32 {
+    let truncated = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || (remainder == half && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+/// Convert a non-negative float with a 5 bit exponent and `mantissa_bits`
+/// bits of mantissa to `f32`.
+fn unpack_float(value: u32, mantissa_bits: u32) -> f32 {
+    let exponent = value >> mantissa_bits;
+    let mantissa = value & ((1 << mantissa_bits) - 1);
+    match exponent {
+        0 => mantissa as f32 * 2.0f32.powi(-14 - mantissa_bits as i32),
+        0x1f if mantissa == 0 => Float::infinity(),
+        0x1f => f32::from_bits(0x7f80_0000 | mantissa << (23 - mantissa_bits) | 0x40_0000),
+        _ => f32::from_bits((exponent + 127 - 15) << 23 | mantissa << (23 - mantissa_bits)),
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The packing functions for arrays, which use const generics.
+
+use super::{pack_small_float, pack_snorm, pack_unorm, unpack_float, unpack_snorm, unpack_unorm};
+
+/// Pack each component into an 8 bit unsigned normalized integer, mapping
+/// `[0, 1]` to `[0, 255]`.
+pub fn pack_unorm8<V: Into<[f32; N]>, const N: usize>(v: V) -> [u8; N] {
+    let v = v.into();
+    let mut packed = [0; N];
+    for i in 0..N {
+        packed[i] = pack_unorm(v[i], 8) as u8;
+    }
+    packed
+}
+
+/// Unpack 8 bit unsigned normalized integers.
+pub fn unpack_unorm8<V: From<[f32; N]>, const N: usize>(packed: [u8; N]) -> V {
+    let mut v = [0.0; N];
+    for i in 0..N {
+        v[i] = unpack_unorm(u32::from(packed[i]), 8);
+    }
+    V::from(v)
+}
+
+/// Pack each component into an 8 bit signed normalized integer, mapping
+/// `[-1, 1]` to `[-127, 127]`.
+pub fn pack_snorm8<V: Into<[f32; N]>, const N: usize>(v: V) -> [i8; N] {
+    let v = v.into();
+    let mut packed = [0; N];
+    for i in 0..N {
+        packed[i] = pack_snorm(v[i], 8) as i8;
+    }
+    packed
+}
+
+/// Unpack 8 bit signed normalized integers. Both `-128` and `-127` unpack
+/// to `-1`.
+pub fn unpack_snorm8<V: From<[f32; N]>, const N: usize>(packed: [i8; N]) -> V {
+    let mut v = [0.0; N];
+    for i in 0..N {
+        v[i] = unpack_snorm(i32::from(packed[i]), 8);
+    }
+    V::from(v)
+}
+
+/// Pack each component into a 16 bit unsigned normalized integer, mapping
+/// `[0, 1]` to `[0, 65535]`.
+pub fn pack_unorm16<V: Into<[f32; N]>, const N: usize>(v: V) -> [u16; N] {
+    let v = v.into();
+    let mut packed = [0; N];
+    for i in 0..N {
+        packed[i] = pack_unorm(v[i], 16) as u16;
+    }
+    packed
+}
+
+/// Unpack 16 bit unsigned normalized integers.
+pub fn unpack_unorm16<V: From<[f32; N]>, const N: usize>(packed: [u16; N]) -> V {
+    let mut v = [0.0; N];
+    for i in 0..N {
+        v[i] = unpack_unorm(u32::from(packed[i]), 16);
+    }
+    V::from(v)
+}
+
+/// Pack each component into a 16 bit signed normalized integer, mapping
+/// `[-1, 1]` to `[-32767, 32767]`.
+pub fn pack_snorm16<V: Into<[f32; N]>, const N: usize>(v: V) -> [i16; N] {
+    let v = v.into();
+    let mut packed = [0; N];
+    for i in 0..N {
+        packed[i] = pack_snorm(v[i], 16) as i16;
+    }
+    packed
+}
+
+/// Unpack 16 bit signed normalized integers. Both `-32768` and `-32767`
+/// unpack to `-1`.
+pub fn unpack_snorm16<V: From<[f32; N]>, const N: usize>(packed: [i16; N]) -> V {
+    let mut v = [0.0; N];
+    for i in 0..N {
+        v[i] = unpack_snorm(i32::from(packed[i]), 16);
+    }
+    V::from(v)
+}
+
+/// Pack each component into an IEEE 754 half precision float.
+///
+/// Values too large for half precision become infinite, and NaN stays NaN.
+pub fn pack_half<V: Into<[f32; N]>, const N: usize>(v: V) -> [u16; N] {
+    let v = v.into();
+    let mut packed = [0; N];
+    for i in 0..N {
+        packed[i] = pack_half_scalar(v[i]);
+    }
+    packed
+}
+
+/// Unpack IEEE 754 half precision floats, which is exact.
+pub fn unpack_half<V: From<[f32; N]>, const N: usize>(packed: [u16; N]) -> V {
+    let mut v = [0.0; N];
+    for i in 0..N {
+        v[i] = unpack_half_scalar(packed[i]);
+    }
+    V::from(v)
+}
+
+fn pack_half_scalar(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) & 0x8000;
+    (sign | pack_small_float(bits & 0x7fff_ffff, 10, false)) as u16
+}
+
+fn unpack_half_scalar(value: u16) -> f32 {
+    let value = u32::from(value);
+    let magnitude = unpack_float(value & 0x7fff, 10);
+    if value & 0x8000 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
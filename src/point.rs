@@ -64,13 +64,57 @@ pub struct Point3<S> {
     pub z: S,
 }
 
+impl<S: BaseNum> Point1<S> {
+    /// Convert from homogeneous coordinates, dividing by `w`.
+    ///
+    /// Returns `None` if `w` is zero, which is a point at infinity.
+    #[inline]
+    pub fn from_homogeneous(v: Vector2<S>) -> Option<Point1<S>> {
+        if v.y.is_zero() {
+            return None;
+        }
+        Some(Point1::new(v.x / v.y))
+    }
+
+    /// Convert to homogeneous coordinates, with a `w` of one.
+    #[inline]
+    pub fn to_homogeneous(self) -> Vector2<S> {
+        Vector2::new(self.x, S::one())
+    }
+}
+
+impl<S: BaseNum> Point2<S> {
+    /// Convert from homogeneous coordinates, dividing by `w`.
+    ///
+    /// Returns `None` if `w` is zero, which is a point at infinity.
+    #[inline]
+    pub fn from_homogeneous(v: Vector3<S>) -> Option<Point2<S>> {
+        if v.z.is_zero() {
+            return None;
+        }
+        Some(Point2::new(v.x / v.z, v.y / v.z))
+    }
+
+    /// Convert to homogeneous coordinates, with a `w` of one.
+    #[inline]
+    pub fn to_homogeneous(self) -> Vector3<S> {
+        Vector3::new(self.x, self.y, S::one())
+    }
+}
+
 impl<S: BaseNum> Point3<S> {
+    /// Convert from homogeneous coordinates, dividing by `w`.
+    ///
+    /// Returns `None` if `w` is zero, which is a point at infinity.
     #[inline]
-    pub fn from_homogeneous(v: Vector4<S>) -> Point3<S> {
-        let e = v.truncate() * (S::one() / v.w);
-        Point3::new(e.x, e.y, e.z) //FIXME
+    pub fn from_homogeneous(v: Vector4<S>) -> Option<Point3<S>> {
+        if v.w.is_zero() {
+            return None;
+        }
+        Some(Point3::new(v.x / v.w, v.y / v.w, v.z / v.w))
     }
 
+    /// Convert to homogeneous coordinates, with a `w` of one.
     #[inline]
     pub fn to_homogeneous(self) -> Vector4<S> {
         Vector4::new(self.x, self.y, self.z, S::one())
@@ -276,6 +320,8 @@ macro_rules! impl_point {
             fn rem_assign(&mut self, scalar) { $(self.$field %= scalar);+ }
         });
 
+        impl<S: BaseNum> ComponentWise for $PointN<S> {}
+
         impl<S: BaseNum> ElementWise for $PointN<S> {
             #[inline] fn add_element_wise(self, rhs: $PointN<S>) -> $PointN<S> { $PointN::new($(self.$field + rhs.$field),+) }
             #[inline] fn sub_element_wise(self, rhs: $PointN<S>) -> $PointN<S> { $PointN::new($(self.$field - rhs.$field),+) }
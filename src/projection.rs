@@ -13,19 +13,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(has_try_from)]
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+
 use num_traits::cast;
 use num_traits::Zero;
 
 use structure::Angle;
 
 use angle::Rad;
-use matrix::Matrix4;
+use matrix::{Matrix3, Matrix4};
 use num::BaseFloat;
 
 /// Create a perspective projection matrix.
 ///
 /// This is the equivalent to the [`gluPerspective`] function.
 ///
+/// The matrix uses OpenGL's clip space conventions. Use `ClipSpace` to target
+/// other graphics APIs.
+///
 /// [`gluPerspective`]: https://www.opengl.org/sdk/docs/man2/xhtml/gluPerspective.xml
 pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
     fovy: A,
@@ -46,6 +54,9 @@ pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
 ///
 /// This is the equivalent of the now deprecated [`glFrustum`] function.
 ///
+/// The matrix uses OpenGL's clip space conventions. Use `ClipSpace` to target
+/// other graphics APIs.
+///
 /// [`glFrustum`]: http://www.opengl.org/sdk/docs/man2/xhtml/glFrustum.xml
 pub fn frustum<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
     Perspective {
@@ -63,6 +74,9 @@ pub fn frustum<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far:
 ///
 /// This is the equivalent of the now deprecated [`glOrtho`] function.
 ///
+/// The matrix uses OpenGL's clip space conventions. Use `ClipSpace` to target
+/// other graphics APIs.
+///
 /// [`glOrtho`]: http://www.opengl.org/sdk/docs/man2/xhtml/glOrtho.xml
 pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
     Ortho {
@@ -76,7 +90,339 @@ pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S
     .into()
 }
 
+/// The range of depth values in normalized device coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DepthRange {
+    /// The near plane maps to a depth of -1, and the far plane to 1, unless
+    /// the depth is reversed.
+    NegativeOneToOne,
+    /// The near plane maps to a depth of 0, and the far plane to 1, unless
+    /// the depth is reversed.
+    ZeroToOne,
+}
+
+/// The direction of the y axis in normalized device coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum YAxis {
+    /// The top of the viewport is at a y of 1.
+    Up,
+    /// The top of the viewport is at a y of -1.
+    Down,
+}
+
+/// The handedness of the view space that is being projected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Handedness {
+    /// The camera looks down the negative z axis, with x to the right and y up.
+    Right,
+    /// The camera looks down the positive z axis, with x to the right and y up.
+    Left,
+}
+
+/// The conventions of the clip space produced by a projection matrix.
+///
+/// The depth range and y axis are fixed by the graphics API, whereas the
+/// handedness of the view space and the depth order are choices of the
+/// application. The associated constants use the usual handedness for each
+/// API, and do not reverse the depth.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ClipSpace {
+    pub depth_range: DepthRange,
+    /// Whether the near plane maps to the far end of the depth range and
+    /// the far plane to the near end, which is known as reverse-Z.
+    pub reverse_z: bool,
+    pub y_axis: YAxis,
+    pub handedness: Handedness,
+}
+
+impl ClipSpace {
+    /// OpenGL: right-handed, with depth in `[-1, 1]` and y up.
+    ///
+    /// This is the convention used by `perspective`, `frustum`, `ortho`, and
+    /// the conversions of the projection types into `Matrix4`.
+    pub const OPENGL: ClipSpace = ClipSpace {
+        depth_range: DepthRange::NegativeOneToOne,
+        reverse_z: false,
+        y_axis: YAxis::Up,
+        handedness: Handedness::Right,
+    };
+
+    /// Vulkan: right-handed, with depth in `[0, 1]` and y down.
+    pub const VULKAN: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        reverse_z: false,
+        y_axis: YAxis::Down,
+        handedness: Handedness::Right,
+    };
+
+    /// Direct3D: left-handed, with depth in `[0, 1]` and y up.
+    pub const DIRECT3D: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        reverse_z: false,
+        y_axis: YAxis::Up,
+        handedness: Handedness::Left,
+    };
+
+    /// Metal: right-handed, with depth in `[0, 1]` and y up.
+    pub const METAL: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        reverse_z: false,
+        y_axis: YAxis::Up,
+        handedness: Handedness::Right,
+    };
+
+    /// WebGPU: right-handed, with depth in `[0, 1]` and y up.
+    pub const WEBGPU: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        reverse_z: false,
+        y_axis: YAxis::Up,
+        handedness: Handedness::Right,
+    };
+
+    /// The same conventions, but with the depth reversed.
+    ///
+    /// Reversing the depth spreads the precision of floating point depth
+    /// buffers evenly over the view distance, especially when combined with
+    /// a `[0, 1]` depth range and an infinite far plane.
+    pub fn reversed(self) -> ClipSpace {
+        ClipSpace {
+            reverse_z: true,
+            ..self
+        }
+    }
+
+    /// Create a perspective projection matrix using these conventions.
+    ///
+    /// See `perspective`.
+    pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
+        self,
+        fovy: A,
+        aspect: S,
+        near: S,
+        far: S,
+    ) -> Matrix4<S> {
+        PerspectiveFov {
+            fovy: fovy.into(),
+            aspect: aspect,
+            near: near,
+            far: far,
+        }
+        .to_matrix(self)
+    }
+
+    /// Create a perspective matrix from a view frustum using these conventions.
+    ///
+    /// See `frustum`.
+    pub fn frustum<S: BaseFloat>(
+        self,
+        left: S,
+        right: S,
+        bottom: S,
+        top: S,
+        near: S,
+        far: S,
+    ) -> Matrix4<S> {
+        Perspective {
+            left: left,
+            right: right,
+            bottom: bottom,
+            top: top,
+            near: near,
+            far: far,
+        }
+        .to_matrix(self)
+    }
+
+    /// Create an orthographic projection matrix using these conventions.
+    ///
+    /// See `ortho`.
+    pub fn ortho<S: BaseFloat>(
+        self,
+        left: S,
+        right: S,
+        bottom: S,
+        top: S,
+        near: S,
+        far: S,
+    ) -> Matrix4<S> {
+        Ortho {
+            left: left,
+            right: right,
+            bottom: bottom,
+            top: top,
+            near: near,
+            far: far,
+        }
+        .to_matrix(self)
+    }
+
+    /// The third row of a right-handed perspective matrix, as `(c2r2, c3r2)`.
+    /// The far plane may be at infinity.
+    fn perspective_depth<S: BaseFloat>(self, near: S, far: S) -> (S, S) {
+        let two: S = cast(2).unwrap();
+        if far.is_infinite() {
+            return match (self.depth_range, self.reverse_z) {
+                (DepthRange::NegativeOneToOne, false) => (-S::one(), -two * near),
+                (DepthRange::NegativeOneToOne, true) => (S::one(), two * near),
+                (DepthRange::ZeroToOne, false) => (-S::one(), -near),
+                (DepthRange::ZeroToOne, true) => (S::zero(), near),
+            };
+        }
+
+        match (self.depth_range, self.reverse_z) {
+            (DepthRange::NegativeOneToOne, false) => (
+                (far + near) / (near - far),
+                (two * far * near) / (near - far),
+            ),
+            (DepthRange::NegativeOneToOne, true) => (
+                (far + near) / (far - near),
+                (two * far * near) / (far - near),
+            ),
+            (DepthRange::ZeroToOne, false) => (far / (near - far), (far * near) / (near - far)),
+            (DepthRange::ZeroToOne, true) => (near / (far - near), (far * near) / (far - near)),
+        }
+    }
+
+    /// The third row of a right-handed orthographic matrix, as `(c2r2, c3r2)`.
+    fn ortho_depth<S: BaseFloat>(self, near: S, far: S) -> (S, S) {
+        let two: S = cast(2).unwrap();
+        match (self.depth_range, self.reverse_z) {
+            (DepthRange::NegativeOneToOne, false) => {
+                (-two / (far - near), -(far + near) / (far - near))
+            }
+            (DepthRange::NegativeOneToOne, true) => {
+                (two / (far - near), (far + near) / (far - near))
+            }
+            (DepthRange::ZeroToOne, false) => (-S::one() / (far - near), -near / (far - near)),
+            (DepthRange::ZeroToOne, true) => (S::one() / (far - near), far / (far - near)),
+        }
+    }
+
+    /// The near and far planes of a right-handed perspective matrix with the
+    /// given third row, as returned by `perspective_depth`.
+    fn perspective_planes<S: BaseFloat>(self, c2r2: S, c3r2: S) -> (S, S) {
+        let (near, far) = match (self.depth_range, self.reverse_z) {
+            (DepthRange::NegativeOneToOne, false) => (c2r2 - S::one(), c2r2 + S::one()),
+            (DepthRange::NegativeOneToOne, true) => (c2r2 + S::one(), c2r2 - S::one()),
+            (DepthRange::ZeroToOne, false) => (c2r2, c2r2 + S::one()),
+            (DepthRange::ZeroToOne, true) => (c2r2 + S::one(), c2r2),
+        };
+        let far = if far.is_zero() {
+            S::infinity()
+        } else {
+            c3r2 / far
+        };
+        (c3r2 / near, far)
+    }
+
+    /// The near and far planes of a right-handed orthographic matrix with the
+    /// given third row, as returned by `ortho_depth`.
+    fn ortho_planes<S: BaseFloat>(self, c2r2: S, c3r2: S) -> (S, S) {
+        match (self.depth_range, self.reverse_z) {
+            (DepthRange::NegativeOneToOne, false) => {
+                ((c3r2 + S::one()) / c2r2, (c3r2 - S::one()) / c2r2)
+            }
+            (DepthRange::NegativeOneToOne, true) => {
+                ((c3r2 - S::one()) / c2r2, (c3r2 + S::one()) / c2r2)
+            }
+            (DepthRange::ZeroToOne, false) => (c3r2 / c2r2, (c3r2 - S::one()) / c2r2),
+            (DepthRange::ZeroToOne, true) => ((c3r2 - S::one()) / c2r2, c3r2 / c2r2),
+        }
+    }
+
+    /// The normalized device depth of a value in the depth buffer, in the
+    /// range `[0, 1]`.
+    fn ndc_depth<S: BaseFloat>(self, depth: S) -> S {
+        match self.depth_range {
+            DepthRange::NegativeOneToOne => depth * cast(2).unwrap() - S::one(),
+            DepthRange::ZeroToOne => depth,
+        }
+    }
+
+    /// The view space z coordinate of a point with the given depth, projected
+    /// by a perspective matrix.
+    fn perspective_view_z<S: BaseFloat>(self, near: S, far: S, depth: S) -> S {
+        let (c2r2, c3r2) = self.perspective_depth(near, far);
+        let z = c3r2 / (depth + c2r2);
+        match self.handedness {
+            Handedness::Right => -z,
+            Handedness::Left => z,
+        }
+    }
+
+    /// The view space z coordinate of a point with the given depth, projected
+    /// by an orthographic matrix.
+    fn ortho_view_z<S: BaseFloat>(self, near: S, far: S, depth: S) -> S {
+        let (c2r2, c3r2) = self.ortho_depth(near, far);
+        let z = (depth - c3r2) / c2r2;
+        match self.handedness {
+            Handedness::Right => z,
+            Handedness::Left => -z,
+        }
+    }
+
+    /// Adapt a right-handed, y up projection matrix to the handedness and
+    /// y axis of this clip space.
+    fn orient<S: BaseFloat>(self, mut m: Matrix4<S>) -> Matrix4<S> {
+        if self.handedness == Handedness::Left {
+            m.z = -m.z;
+        }
+        if self.y_axis == YAxis::Down {
+            m.x.y = -m.x.y;
+            m.y.y = -m.y.y;
+            m.z.y = -m.z.y;
+            m.w.y = -m.w.y;
+        }
+        m
+    }
+
+    /// Adapt the inverse of a right-handed, y up projection matrix to the
+    /// handedness and y axis of this clip space.
+    fn orient_inverse<S: BaseFloat>(self, mut m: Matrix4<S>) -> Matrix4<S> {
+        if self.handedness == Handedness::Left {
+            m.x.z = -m.x.z;
+            m.y.z = -m.y.z;
+            m.z.z = -m.z.z;
+            m.w.z = -m.w.z;
+        }
+        if self.y_axis == YAxis::Down {
+            m.y = -m.y;
+        }
+        m
+    }
+}
+
+/// The inverse of the perspective matrix with the given coefficients.
+fn perspective_inverse<S: BaseFloat>(
+    clip_space: ClipSpace,
+    c0r0: S,
+    c1r1: S,
+    c2r0: S,
+    c2r1: S,
+    (c2r2, c3r2): (S, S),
+) -> Matrix4<S> {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    clip_space.orient_inverse(Matrix4::new(
+        S::one() / c0r0, S::zero(), S::zero(), S::zero(),
+        S::zero(), S::one() / c1r1, S::zero(), S::zero(),
+        S::zero(), S::zero(), S::zero(), S::one() / c3r2,
+        c2r0 / c0r0, c2r1 / c1r1, -S::one(), c2r2 / c3r2,
+    ))
+}
+
+impl Default for ClipSpace {
+    fn default() -> ClipSpace {
+        ClipSpace::OPENGL
+    }
+}
+
 /// A perspective projection based on a vertical field-of-view angle.
+///
+/// The far plane may be at infinity, by setting `far` to `S::infinity()`.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -103,10 +449,10 @@ impl<S: BaseFloat> PerspectiveFov<S> {
             far: self.far.clone(),
         }
     }
-}
 
-impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
-    fn from(persp: PerspectiveFov<S>) -> Matrix4<S> {
+    /// Create the projection matrix for the given clip space conventions.
+    pub fn to_matrix(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        let persp = self;
         assert!(
             persp.fovy > Rad::zero(),
             "The vertical field of view cannot be below zero, found: {:?}",
@@ -152,27 +498,69 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
         let c1r2 = S::zero();
         let c1r3 = S::zero();
 
+        let (c2r2, c3r2) = clip_space.perspective_depth(persp.near, persp.far);
+
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = (persp.far + persp.near) / (persp.near - persp.far);
         let c2r3 = -S::one();
 
         let c3r0 = S::zero();
         let c3r1 = S::zero();
-        let c3r2 = (two * persp.far * persp.near) / (persp.near - persp.far);
         let c3r3 = S::zero();
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
+        clip_space.orient(Matrix4::new(
             c0r0, c0r1, c0r2, c0r3,
             c1r0, c1r1, c1r2, c1r3,
             c2r0, c2r1, c2r2, c2r3,
             c3r0, c3r1, c3r2, c3r3,
+        ))
+    }
+}
+
+impl<S: BaseFloat> PerspectiveFov<S> {
+    /// Create the inverse of the projection matrix for the given clip space
+    /// conventions. This is computed directly from the parameters, which is
+    /// faster and more precise than inverting the projection matrix.
+    pub fn inverse(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+        let f = Rad::cot(self.fovy / two);
+        perspective_inverse(
+            clip_space,
+            f / self.aspect,
+            f,
+            S::zero(),
+            S::zero(),
+            clip_space.perspective_depth(self.near, self.far),
         )
     }
+
+    /// Recover the view space z coordinate from a depth in normalized device
+    /// coordinates, using the given clip space conventions.
+    ///
+    /// For a value read from the depth buffer, use `linearize_depth_buffer`.
+    pub fn linearize_depth(&self, clip_space: ClipSpace, depth: S) -> S {
+        clip_space.perspective_view_z(self.near, self.far, depth)
+    }
+
+    /// Recover the view space z coordinate from a value in the depth buffer,
+    /// using the given clip space conventions.
+    ///
+    /// See `Perspective::linearize_depth_buffer`.
+    pub fn linearize_depth_buffer(&self, clip_space: ClipSpace, depth: S) -> S {
+        self.linearize_depth(clip_space, clip_space.ndc_depth(depth))
+    }
+}
+
+impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
+    fn from(persp: PerspectiveFov<S>) -> Matrix4<S> {
+        persp.to_matrix(ClipSpace::OPENGL)
+    }
 }
 
 /// A perspective projection with arbitrary left/right/bottom/top distances
+///
+/// The far plane may be at infinity, by setting `far` to `S::infinity()`.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Perspective<S> {
@@ -184,8 +572,10 @@ pub struct Perspective<S> {
     pub far: S,
 }
 
-impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
-    fn from(persp: Perspective<S>) -> Matrix4<S> {
+impl<S: BaseFloat> Perspective<S> {
+    /// Create the projection matrix for the given clip space conventions.
+    pub fn to_matrix(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        let persp = self;
         assert!(
             persp.left <= persp.right,
             "`left` cannot be greater than `right`, found: left: {:?} right: {:?}",
@@ -217,24 +607,162 @@ impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
         let c1r2 = S::zero();
         let c1r3 = S::zero();
 
+        let (c2r2, c3r2) = clip_space.perspective_depth(persp.near, persp.far);
+
         let c2r0 = (persp.right + persp.left) / (persp.right - persp.left);
         let c2r1 = (persp.top + persp.bottom) / (persp.top - persp.bottom);
-        let c2r2 = -(persp.far + persp.near) / (persp.far - persp.near);
         let c2r3 = -S::one();
 
         let c3r0 = S::zero();
         let c3r1 = S::zero();
-        let c3r2 = -(two * persp.far * persp.near) / (persp.far - persp.near);
         let c3r3 = S::zero();
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
+        clip_space.orient(Matrix4::new(
             c0r0, c0r1, c0r2, c0r3,
             c1r0, c1r1, c1r2, c1r3,
             c2r0, c2r1, c2r2, c2r3,
             c3r0, c3r1, c3r2, c3r3,
+        ))
+    }
+}
+
+impl<S: BaseFloat> Perspective<S> {
+    /// Create the inverse of the projection matrix for the given clip space
+    /// conventions. This is computed directly from the parameters, which is
+    /// faster and more precise than inverting the projection matrix.
+    pub fn inverse(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+        perspective_inverse(
+            clip_space,
+            (two * self.near) / (self.right - self.left),
+            (two * self.near) / (self.top - self.bottom),
+            (self.right + self.left) / (self.right - self.left),
+            (self.top + self.bottom) / (self.top - self.bottom),
+            clip_space.perspective_depth(self.near, self.far),
         )
     }
+
+    /// Recover the view space z coordinate from a depth in normalized device
+    /// coordinates, using the given clip space conventions.
+    ///
+    /// For a value read from the depth buffer, use `linearize_depth_buffer`.
+    pub fn linearize_depth(&self, clip_space: ClipSpace, depth: S) -> S {
+        clip_space.perspective_view_z(self.near, self.far, depth)
+    }
+
+    /// Recover the view space z coordinate from a value in the depth buffer,
+    /// using the given clip space conventions.
+    ///
+    /// The value is in the range `[0, 1]`, and is mapped to the depth range of
+    /// the clip space. This assumes the default depth bounds of the viewport,
+    /// such as `glDepthRange(0, 1)`.
+    pub fn linearize_depth_buffer(&self, clip_space: ClipSpace, depth: S) -> S {
+        self.linearize_depth(clip_space, clip_space.ndc_depth(depth))
+    }
+}
+
+impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
+    fn from(persp: Perspective<S>) -> Matrix4<S> {
+        persp.to_matrix(ClipSpace::OPENGL)
+    }
+}
+
+/// A perspective projection based on four field-of-view angles, as used for
+/// the eyes of virtual reality headsets.
+///
+/// The angles are measured from the view direction, and are positive towards
+/// the right and the top, so `left` and `down` are usually negative. The far
+/// plane may be at infinity, by setting `far` to `S::infinity()`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AsymmetricFov<S> {
+    pub left: Rad<S>,
+    pub right: Rad<S>,
+    pub up: Rad<S>,
+    pub down: Rad<S>,
+    pub near: S,
+    pub far: S,
+}
+
+impl<S: BaseFloat> AsymmetricFov<S> {
+    /// Create the projection from the tangents of the four angles, as
+    /// reported by some virtual reality runtimes.
+    pub fn from_tangents(left: S, right: S, up: S, down: S, near: S, far: S) -> AsymmetricFov<S> {
+        AsymmetricFov {
+            left: Rad::atan(left),
+            right: Rad::atan(right),
+            up: Rad::atan(up),
+            down: Rad::atan(down),
+            near: near,
+            far: far,
+        }
+    }
+
+    pub fn to_perspective(&self) -> Perspective<S> {
+        let fov = self;
+        let quarter_turn = Rad::turn_div_4();
+        assert!(
+            fov.left > -quarter_turn && fov.right < quarter_turn,
+            "The horizontal angles must be within a quarter turn, found: left: {:?} right: {:?}",
+            fov.left,
+            fov.right
+        );
+        assert!(
+            fov.down > -quarter_turn && fov.up < quarter_turn,
+            "The vertical angles must be within a quarter turn, found: up: {:?} down: {:?}",
+            fov.up,
+            fov.down
+        );
+        assert!(
+            fov.near > S::zero(),
+            "The near plane distance cannot be below zero, found: {:?}",
+            fov.near
+        );
+
+        Perspective {
+            left: fov.near * Rad::tan(fov.left),
+            right: fov.near * Rad::tan(fov.right),
+            bottom: fov.near * Rad::tan(fov.down),
+            top: fov.near * Rad::tan(fov.up),
+            near: fov.near,
+            far: fov.far,
+        }
+    }
+
+    /// Create the projection matrix for the given clip space conventions.
+    pub fn to_matrix(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        self.to_perspective().to_matrix(clip_space)
+    }
+
+    /// Create the inverse of the projection matrix for the given clip space
+    /// conventions. This is computed directly from the parameters, which is
+    /// faster and more precise than inverting the projection matrix.
+    pub fn inverse(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        self.to_perspective().inverse(clip_space)
+    }
+
+    /// Recover the view space z coordinate from a depth in normalized device
+    /// coordinates, using the given clip space conventions.
+    ///
+    /// See `Perspective::linearize_depth`.
+    pub fn linearize_depth(&self, clip_space: ClipSpace, depth: S) -> S {
+        clip_space.perspective_view_z(self.near, self.far, depth)
+    }
+
+    /// Recover the view space z coordinate from a value in the depth buffer,
+    /// using the given clip space conventions.
+    ///
+    /// See `Perspective::linearize_depth_buffer`.
+    pub fn linearize_depth_buffer(&self, clip_space: ClipSpace, depth: S) -> S {
+        self.linearize_depth(clip_space, clip_space.ndc_depth(depth))
+    }
+}
+
+impl<S: BaseFloat> From<AsymmetricFov<S>> for Matrix4<S> {
+    fn from(fov: AsymmetricFov<S>) -> Matrix4<S> {
+        fov.to_matrix(ClipSpace::OPENGL)
+    }
 }
 
 /// An orthographic projection with arbitrary left/right/bottom/top distances
@@ -249,8 +777,10 @@ pub struct Ortho<S> {
     pub far: S,
 }
 
-impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
-    fn from(ortho: Ortho<S>) -> Matrix4<S> {
+impl<S: BaseFloat> Ortho<S> {
+    /// Create the projection matrix for the given clip space conventions.
+    pub fn to_matrix(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        let ortho = self;
         let two: S = cast(2).unwrap();
 
         let c0r0 = two / (ortho.right - ortho.left);
@@ -263,22 +793,376 @@ impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
         let c1r2 = S::zero();
         let c1r3 = S::zero();
 
+        let (c2r2, c3r2) = clip_space.ortho_depth(ortho.near, ortho.far);
+
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = -two / (ortho.far - ortho.near);
         let c2r3 = S::zero();
 
         let c3r0 = -(ortho.right + ortho.left) / (ortho.right - ortho.left);
         let c3r1 = -(ortho.top + ortho.bottom) / (ortho.top - ortho.bottom);
-        let c3r2 = -(ortho.far + ortho.near) / (ortho.far - ortho.near);
         let c3r3 = S::one();
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
+        clip_space.orient(Matrix4::new(
             c0r0, c0r1, c0r2, c0r3,
             c1r0, c1r1, c1r2, c1r3,
             c2r0, c2r1, c2r2, c2r3,
             c3r0, c3r1, c3r2, c3r3,
+        ))
+    }
+}
+
+impl<S: BaseFloat> Ortho<S> {
+    /// Create the inverse of the projection matrix for the given clip space
+    /// conventions. This is computed directly from the parameters, which is
+    /// faster and more precise than inverting the projection matrix.
+    pub fn inverse(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+        let (c2r2, c3r2) = clip_space.ortho_depth(self.near, self.far);
+
+        let c0r0 = (self.right - self.left) / two;
+        let c1r1 = (self.top - self.bottom) / two;
+        let c2r2_inv = S::one() / c2r2;
+        let c3r0 = (self.right + self.left) / two;
+        let c3r1 = (self.top + self.bottom) / two;
+        let c3r2_inv = -c3r2 / c2r2;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        clip_space.orient_inverse(Matrix4::new(
+            c0r0, S::zero(), S::zero(), S::zero(),
+            S::zero(), c1r1, S::zero(), S::zero(),
+            S::zero(), S::zero(), c2r2_inv, S::zero(),
+            c3r0, c3r1, c3r2_inv, S::one(),
+        ))
+    }
+
+    /// Recover the view space z coordinate from a depth in normalized device
+    /// coordinates, using the given clip space conventions.
+    ///
+    /// For a value read from the depth buffer, use `linearize_depth_buffer`.
+    pub fn linearize_depth(&self, clip_space: ClipSpace, depth: S) -> S {
+        clip_space.ortho_view_z(self.near, self.far, depth)
+    }
+
+    /// Recover the view space z coordinate from a value in the depth buffer,
+    /// using the given clip space conventions.
+    ///
+    /// The value is in the range `[0, 1]`, and is mapped to the depth range of
+    /// the clip space. This assumes the default depth bounds of the viewport,
+    /// such as `glDepthRange(0, 1)`.
+    pub fn linearize_depth_buffer(&self, clip_space: ClipSpace, depth: S) -> S {
+        self.linearize_depth(clip_space, clip_space.ndc_depth(depth))
+    }
+}
+
+impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
+    fn from(ortho: Ortho<S>) -> Matrix4<S> {
+        ortho.to_matrix(ClipSpace::OPENGL)
+    }
+}
+
+/// An orthographic projection for 2D rendering to a screen or window, such as
+/// for user interfaces.
+///
+/// Positions are in pixels, with the origin at the top left of the screen and
+/// y pointing down. A z of zero is halfway between the near and far planes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScreenOrtho<S> {
+    pub width: S,
+    pub height: S,
+}
+
+impl<S: BaseFloat> ScreenOrtho<S> {
+    pub fn to_ortho(&self) -> Ortho<S> {
+        Ortho {
+            left: S::zero(),
+            right: self.width,
+            bottom: self.height,
+            top: S::zero(),
+            near: -S::one(),
+            far: S::one(),
+        }
+    }
+
+    /// Create the projection matrix for the given clip space conventions.
+    pub fn to_matrix(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        self.to_ortho().to_matrix(clip_space)
+    }
+
+    /// Create a 2D homogeneous projection matrix for the given clip space
+    /// conventions, mapping pixel positions to the x and y of normalized
+    /// device coordinates.
+    pub fn to_matrix3(&self, clip_space: ClipSpace) -> Matrix3<S> {
+        let two: S = cast(2).unwrap();
+        let (sy, ty) = match clip_space.y_axis {
+            YAxis::Up => (-two / self.height, S::one()),
+            YAxis::Down => (two / self.height, -S::one()),
+        };
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix3::new(
+            two / self.width, S::zero(), S::zero(),
+            S::zero(), sy, S::zero(),
+            -S::one(), ty, S::one(),
         )
     }
 }
+
+impl<S: BaseFloat> From<ScreenOrtho<S>> for Matrix4<S> {
+    fn from(screen: ScreenOrtho<S>) -> Matrix4<S> {
+        screen.to_matrix(ClipSpace::OPENGL)
+    }
+}
+
+/// A projection recovered from a matrix by `Projection::from_matrix`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Projection<S> {
+    /// A symmetric perspective projection.
+    PerspectiveFov(PerspectiveFov<S>),
+    /// An off-center perspective projection.
+    Perspective(Perspective<S>),
+    /// An orthographic projection.
+    Ortho(Ortho<S>),
+}
+
+impl<S: BaseFloat> Projection<S> {
+    /// Recover the parameters of a projection matrix created with the given
+    /// clip space conventions, detecting which kind of projection it is.
+    ///
+    /// Perspective projections are returned as `PerspectiveFov` when they are
+    /// symmetric, and as `Perspective` otherwise. The matrix may be scaled by
+    /// any non-zero factor, as this does not change the projection.
+    ///
+    /// Entries that are zero in an exact projection matrix may contain small
+    /// rounding errors, relative to the scale of the matrix.
+    pub fn from_matrix(
+        m: Matrix4<S>,
+        clip_space: ClipSpace,
+    ) -> Result<Projection<S>, ProjectionError> {
+        match normalize_projection(clip_space, m) {
+            Some((m, true)) if m.z.x.is_zero() && m.z.y.is_zero() => {
+                perspective_fov_from_normalized(clip_space, m).map(Projection::PerspectiveFov)
+            }
+            Some((m, true)) => {
+                perspective_from_normalized(clip_space, m).map(Projection::Perspective)
+            }
+            Some((m, false)) => ortho_from_normalized(clip_space, m).map(Projection::Ortho),
+            None => Err(ProjectionError::Unrecognized),
+        }
+    }
+
+    /// Create the projection matrix for the given clip space conventions.
+    pub fn to_matrix(&self, clip_space: ClipSpace) -> Matrix4<S> {
+        match *self {
+            Projection::PerspectiveFov(ref persp) => persp.to_matrix(clip_space),
+            Projection::Perspective(ref persp) => persp.to_matrix(clip_space),
+            Projection::Ortho(ref ortho) => ortho.to_matrix(clip_space),
+        }
+    }
+}
+
+impl<S: BaseFloat> PerspectiveFov<S> {
+    /// Recover the parameters of a symmetric perspective projection matrix
+    /// created with the given clip space conventions.
+    pub fn from_matrix(
+        m: Matrix4<S>,
+        clip_space: ClipSpace,
+    ) -> Result<PerspectiveFov<S>, ProjectionError> {
+        match normalize_projection(clip_space, m) {
+            Some((m, true)) => perspective_fov_from_normalized(clip_space, m),
+            _ => Err(ProjectionError::Unrecognized),
+        }
+    }
+}
+
+impl<S: BaseFloat> Perspective<S> {
+    /// Recover the parameters of a perspective projection matrix created with
+    /// the given clip space conventions.
+    pub fn from_matrix(
+        m: Matrix4<S>,
+        clip_space: ClipSpace,
+    ) -> Result<Perspective<S>, ProjectionError> {
+        match normalize_projection(clip_space, m) {
+            Some((m, true)) => perspective_from_normalized(clip_space, m),
+            _ => Err(ProjectionError::Unrecognized),
+        }
+    }
+}
+
+impl<S: BaseFloat> Ortho<S> {
+    /// Recover the parameters of an orthographic projection matrix created
+    /// with the given clip space conventions.
+    pub fn from_matrix(m: Matrix4<S>, clip_space: ClipSpace) -> Result<Ortho<S>, ProjectionError> {
+        match normalize_projection(clip_space, m) {
+            Some((m, false)) => ortho_from_normalized(clip_space, m),
+            _ => Err(ProjectionError::Unrecognized),
+        }
+    }
+}
+
+#[cfg(has_try_from)]
+impl<S: BaseFloat> TryFrom<Matrix4<S>> for Projection<S> {
+    type Error = ProjectionError;
+
+    fn try_from(m: Matrix4<S>) -> Result<Projection<S>, ProjectionError> {
+        Projection::from_matrix(m, ClipSpace::OPENGL)
+    }
+}
+
+#[cfg(has_try_from)]
+impl<S: BaseFloat> TryFrom<Matrix4<S>> for PerspectiveFov<S> {
+    type Error = ProjectionError;
+
+    fn try_from(m: Matrix4<S>) -> Result<PerspectiveFov<S>, ProjectionError> {
+        PerspectiveFov::from_matrix(m, ClipSpace::OPENGL)
+    }
+}
+
+#[cfg(has_try_from)]
+impl<S: BaseFloat> TryFrom<Matrix4<S>> for Perspective<S> {
+    type Error = ProjectionError;
+
+    fn try_from(m: Matrix4<S>) -> Result<Perspective<S>, ProjectionError> {
+        Perspective::from_matrix(m, ClipSpace::OPENGL)
+    }
+}
+
+#[cfg(has_try_from)]
+impl<S: BaseFloat> TryFrom<Matrix4<S>> for Ortho<S> {
+    type Error = ProjectionError;
+
+    fn try_from(m: Matrix4<S>) -> Result<Ortho<S>, ProjectionError> {
+        Ortho::from_matrix(m, ClipSpace::OPENGL)
+    }
+}
+
+/// The error returned when the parameters of a projection cannot be recovered
+/// from a matrix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProjectionError {
+    /// The matrix is not a projection of the requested kind.
+    Unrecognized,
+    /// The perspective projection is off-center, so it cannot be described by
+    /// a field of view and aspect ratio.
+    OffCenter,
+    /// The matrix has the form of a projection, but its planes are degenerate,
+    /// or do not match the clip space conventions.
+    InvalidParameters,
+}
+
+impl fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ProjectionError::Unrecognized => "the matrix is not a recognized projection",
+            ProjectionError::OffCenter => "the perspective projection is off-center",
+            ProjectionError::InvalidParameters => "the projection has invalid planes",
+        })
+    }
+}
+
+impl error::Error for ProjectionError {}
+
+/// Undo the orientation of a projection matrix, and scale it so that its last
+/// row is `[0, 0, -1, 0]` for a perspective projection, or `[0, 0, 0, 1]` for
+/// an orthographic projection. The flag is `true` for a perspective projection.
+fn normalize_projection<S: BaseFloat>(
+    clip_space: ClipSpace,
+    m: Matrix4<S>,
+) -> Option<(Matrix4<S>, bool)> {
+    let m = snap_to_zero(clip_space.orient(m));
+    let diagonal_xy = m.x.y.is_zero()
+        && m.x.z.is_zero()
+        && m.x.w.is_zero()
+        && m.y.x.is_zero()
+        && m.y.z.is_zero()
+        && m.y.w.is_zero();
+    if !m.is_finite() || !diagonal_xy {
+        return None;
+    }
+
+    if !m.z.w.is_zero() && m.w.x.is_zero() && m.w.y.is_zero() && m.w.w.is_zero() {
+        Some((m / -m.z.w, true))
+    } else if m.z.w.is_zero() && m.z.x.is_zero() && m.z.y.is_zero() && !m.w.w.is_zero() {
+        Some((m / m.w.w, false))
+    } else {
+        None
+    }
+}
+
+/// Set the entries of a projection matrix that are zero in the matrices
+/// created by this module to exactly zero, when they are within a tolerance
+/// relative to the largest of the diagonal `x` and `y` scales and the `w`
+/// entries. Matrices that went through other code, such as `(P * V) * V⁻¹`,
+/// often have tiny rounding errors there.
+fn snap_to_zero<S: BaseFloat>(m: Matrix4<S>) -> Matrix4<S> {
+    let scale = m.x.x.abs().max(m.y.y.abs()).max(m.z.w.abs()).max(m.w.w.abs());
+    let tolerance = scale * S::default_epsilon() * cast(16).unwrap();
+    let snap = |value: S| if value.abs() <= tolerance { S::zero() } else { value };
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    Matrix4::new(
+        m.x.x, snap(m.x.y), snap(m.x.z), snap(m.x.w),
+        snap(m.y.x), m.y.y, snap(m.y.z), snap(m.y.w),
+        snap(m.z.x), snap(m.z.y), m.z.z, snap(m.z.w),
+        snap(m.w.x), snap(m.w.y), m.w.z, snap(m.w.w),
+    )
+}
+
+fn perspective_fov_from_normalized<S: BaseFloat>(
+    clip_space: ClipSpace,
+    m: Matrix4<S>,
+) -> Result<PerspectiveFov<S>, ProjectionError> {
+    if !m.z.x.is_zero() || !m.z.y.is_zero() {
+        return Err(ProjectionError::OffCenter);
+    }
+    let persp = perspective_from_normalized(clip_space, m)?;
+
+    let two: S = cast(2).unwrap();
+    Ok(PerspectiveFov {
+        fovy: Rad::atan(S::one() / m.y.y) * two,
+        aspect: m.y.y / m.x.x,
+        near: persp.near,
+        far: persp.far,
+    })
+}
+
+fn perspective_from_normalized<S: BaseFloat>(
+    clip_space: ClipSpace,
+    m: Matrix4<S>,
+) -> Result<Perspective<S>, ProjectionError> {
+    let (near, far) = clip_space.perspective_planes(m.z.z, m.w.z);
+    if !(near > S::zero() && far > near && m.x.x > S::zero() && m.y.y > S::zero()) {
+        return Err(ProjectionError::InvalidParameters);
+    }
+
+    Ok(Perspective {
+        left: (m.z.x - S::one()) * near / m.x.x,
+        right: (m.z.x + S::one()) * near / m.x.x,
+        bottom: (m.z.y - S::one()) * near / m.y.y,
+        top: (m.z.y + S::one()) * near / m.y.y,
+        near: near,
+        far: far,
+    })
+}
+
+fn ortho_from_normalized<S: BaseFloat>(
+    clip_space: ClipSpace,
+    m: Matrix4<S>,
+) -> Result<Ortho<S>, ProjectionError> {
+    if !(m.x.x > S::zero() && m.y.y > S::zero()) || m.z.z.is_zero() {
+        return Err(ProjectionError::InvalidParameters);
+    }
+    let (near, far) = clip_space.ortho_planes(m.z.z, m.w.z);
+
+    Ok(Ortho {
+        left: (-S::one() - m.w.x) / m.x.x,
+        right: (S::one() - m.w.x) / m.x.x,
+        bottom: (-S::one() - m.w.y) / m.y.y,
+        top: (S::one() - m.w.y) / m.y.y,
+        near: near,
+        far: far,
+    })
+}
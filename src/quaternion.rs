@@ -33,6 +33,7 @@ use matrix::{Matrix3, Matrix4};
 use num::BaseFloat;
 use point::Point3;
 use rotation::{Basis3, Rotation, Rotation3};
+use unit::Unit;
 use vector::Vector3;
 
 #[cfg(feature = "mint")]
@@ -79,7 +80,7 @@ impl<S: BaseFloat> Quaternion<S> {
     pub fn from_arc(
         src: Vector3<S>,
         dst: Vector3<S>,
-        fallback: Option<Vector3<S>>,
+        fallback: Option<Unit<Vector3<S>>>,
     ) -> Quaternion<S> {
         let mag_avg = (src.magnitude2() * dst.magnitude2()).sqrt();
         let dot = src.dot(dst);
@@ -91,7 +92,7 @@ impl<S: BaseFloat> Quaternion<S> {
                 if ulps_eq!(v, &Zero::zero()) {
                     v = Vector3::unit_y().cross(src);
                 }
-                v.normalize()
+                Unit::new_unchecked(v.normalize())
             });
             Quaternion::from_axis_angle(axis, Rad::turn_div_2())
         } else {
@@ -234,8 +235,7 @@ impl<S: NumCast + Copy> Quaternion<S> {
 }
 
 impl<S: BaseFloat> InnerSpace for Quaternion<S> {
-    #[inline]
-    default_fn!( dot(self, other: Quaternion<S>) -> S {
+    default_fn!(#[inline] dot(self, other: Quaternion<S>) -> S {
         self.s * other.s + self.v.dot(other.v)
     } );
 }
@@ -520,9 +520,9 @@ impl<S: BaseFloat> Rotation<Point3<S>> for Quaternion<S> {
 
 impl<S: BaseFloat> Rotation3<S> for Quaternion<S> {
     #[inline]
-    fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> Quaternion<S> {
+    fn from_axis_angle<A: Into<Rad<S>>>(axis: Unit<Vector3<S>>, angle: A) -> Quaternion<S> {
         let (s, c) = Rad::sin_cos(angle.into() * cast(0.5f64).unwrap());
-        Quaternion::from_sv(c, axis * s)
+        Quaternion::from_sv(c, axis.into_inner() * s)
     }
 }
 
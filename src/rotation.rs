@@ -26,6 +26,7 @@ use matrix::{Matrix2, Matrix3};
 use num::BaseFloat;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
+use unit::Unit;
 use vector::{Vector2, Vector3};
 
 /// A trait for a generic rotation. A rotation is a transformation that
@@ -75,26 +76,54 @@ pub trait Rotation3<S: BaseFloat>:
     Rotation<Point3<S>> + Into<Matrix3<S>> + Into<Basis3<S>> + Into<Quaternion<S>> + From<Euler<Rad<S>>>
 {
     /// Create a rotation using an angle around a given axis.
-    ///
-    /// The specified axis **must be normalized**, or it represents an invalid rotation.
-    fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> Self;
+    fn from_axis_angle<A: Into<Rad<S>>>(axis: Unit<Vector3<S>>, angle: A) -> Self;
 
     /// Create a rotation from an angle around the `x` axis (pitch).
     #[inline]
     fn from_angle_x<A: Into<Rad<S>>>(theta: A) -> Self {
-        Rotation3::from_axis_angle(Vector3::unit_x(), theta)
+        Rotation3::from_axis_angle(Unit::unit_x(), theta)
     }
 
     /// Create a rotation from an angle around the `y` axis (yaw).
     #[inline]
     fn from_angle_y<A: Into<Rad<S>>>(theta: A) -> Self {
-        Rotation3::from_axis_angle(Vector3::unit_y(), theta)
+        Rotation3::from_axis_angle(Unit::unit_y(), theta)
     }
 
     /// Create a rotation from an angle around the `z` axis (roll).
     #[inline]
     fn from_angle_z<A: Into<Rad<S>>>(theta: A) -> Self {
-        Rotation3::from_axis_angle(Vector3::unit_z(), theta)
+        Rotation3::from_axis_angle(Unit::unit_z(), theta)
+    }
+
+    /// Create the rotation of a right-handed view transform, for a camera
+    /// looking in the direction `dir`, using `up` for orientation. This
+    /// rotates `dir` onto the negative `z` axis.
+    #[inline]
+    fn look_to_rh(dir: Vector3<S>, up: Vector3<S>) -> Self {
+        Rotation::look_at(-dir, up)
+    }
+
+    /// Create the rotation of a left-handed view transform, for a camera
+    /// looking in the direction `dir`, using `up` for orientation. This
+    /// rotates `dir` onto the positive `z` axis, like `look_at`.
+    #[inline]
+    fn look_to_lh(dir: Vector3<S>, up: Vector3<S>) -> Self {
+        Rotation::look_at(dir, up)
+    }
+
+    /// Create the rotation of a right-handed view transform, for a camera at
+    /// `eye` looking at `center`, using `up` for orientation.
+    #[inline]
+    fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Self {
+        Rotation3::look_to_rh(center - eye, up)
+    }
+
+    /// Create the rotation of a left-handed view transform, for a camera at
+    /// `eye` looking at `center`, using `up` for orientation.
+    #[inline]
+    fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Self {
+        Rotation3::look_to_lh(center - eye, up)
     }
 }
 
@@ -415,7 +444,7 @@ impl<S: BaseFloat> approx::UlpsEq for Basis3<S> {
 }
 
 impl<S: BaseFloat> Rotation3<S> for Basis3<S> {
-    fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> Basis3<S> {
+    fn from_axis_angle<A: Into<Rad<S>>>(axis: Unit<Vector3<S>>, angle: A) -> Basis3<S> {
         Basis3 {
             mat: Matrix3::from_axis_angle(axis, angle),
         }
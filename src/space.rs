@@ -0,0 +1,281 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Opt-in types that tag points, vectors and transforms with the coordinate
+//! space they are in, so that mixing up spaces is a compile error.
+//!
+//! A space is any type, usually an empty enum. `InSpace<T, Space>` wraps a
+//! point or vector, and `SpaceTransform<T, P, Src, Dst>` wraps a transform of
+//! points of type `P` from `Src` to `Dst`. Both have the same representation
+//! as the wrapped type, and `into_inner` gives back the plain value.
+//!
+//! ```rust
+//! use cgmath::space::{InSpace, SpaceTransform};
+//! use cgmath::{Matrix4, Point3, Vector3};
+//!
+//! enum Local {}
+//! enum World {}
+//!
+//! let model: SpaceTransform<_, Point3<f64>, Local, World> =
+//!     SpaceTransform::new(Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)));
+//! let p: InSpace<_, Local> = InSpace::new(Point3::new(0.0, 0.0, 1.0));
+//! let q: InSpace<Point3<f64>, World> = model.transform_point(p);
+//! assert_eq!(q.into_inner(), Point3::new(1.0, 2.0, 4.0));
+//! ```
+//!
+//! Transforming a point from the wrong space does not compile:
+//!
+//! ```rust,compile_fail
+//! # use cgmath::space::{InSpace, SpaceTransform};
+//! # use cgmath::{Matrix4, Point3};
+//! # enum Local {}
+//! # enum World {}
+//! let model: SpaceTransform<Matrix4<f64>, Point3<f64>, Local, World> =
+//!     SpaceTransform::new(Matrix4::from_scale(2.0));
+//! let p: InSpace<_, World> = InSpace::new(Point3::new(0.0, 0.0, 1.0));
+//! model.transform_point(p);
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::*;
+
+use structure::*;
+use transform::Transform;
+
+use num::BaseNum;
+use vector::Vector3;
+
+/// A point or vector in the coordinate space `Space`.
+#[repr(transparent)]
+pub struct InSpace<T, Space> {
+    value: T,
+    space: PhantomData<fn() -> Space>,
+}
+
+impl<T, Space> InSpace<T, Space> {
+    /// Tag a point or vector with the space it is in.
+    #[inline]
+    pub fn new(value: T) -> InSpace<T, Space> {
+        InSpace {
+            value: value,
+            space: PhantomData,
+        }
+    }
+
+    /// The plain point or vector.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<V: InnerSpace, Space> InSpace<V, Space> {
+    /// The dot product with another vector in the same space.
+    #[inline]
+    pub fn dot(self, other: InSpace<V, Space>) -> V::Scalar {
+        self.value.dot(other.value)
+    }
+
+    /// The length of the vector.
+    #[inline]
+    pub fn magnitude(self) -> V::Scalar
+    where
+        V::Scalar: ::num_traits::Float,
+    {
+        self.value.magnitude()
+    }
+
+    /// The vector with the same direction and a length of `1`.
+    #[inline]
+    pub fn normalize(self) -> InSpace<V, Space>
+    where
+        V::Scalar: ::num_traits::Float,
+    {
+        InSpace::new(self.value.normalize())
+    }
+}
+
+impl<S: BaseNum, Space> InSpace<Vector3<S>, Space> {
+    /// The cross product with another vector in the same space.
+    #[inline]
+    pub fn cross(self, other: InSpace<Vector3<S>, Space>) -> InSpace<Vector3<S>, Space> {
+        InSpace::new(self.value.cross(other.value))
+    }
+}
+
+impl<T: Copy, Space> Copy for InSpace<T, Space> {}
+
+impl<T: Clone, Space> Clone for InSpace<T, Space> {
+    #[inline]
+    fn clone(&self) -> InSpace<T, Space> {
+        InSpace::new(self.value.clone())
+    }
+}
+
+impl<T: PartialEq, Space> PartialEq for InSpace<T, Space> {
+    #[inline]
+    fn eq(&self, other: &InSpace<T, Space>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: fmt::Debug, Space> fmt::Debug for InSpace<T, Space> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+// Points and vectors combine only within the same space, so that for example
+// `Point3 - Point3` gives a `Vector3` in the space of both points.
+
+impl<T: Add<U>, U, Space> Add<InSpace<U, Space>> for InSpace<T, Space> {
+    type Output = InSpace<T::Output, Space>;
+
+    #[inline]
+    fn add(self, other: InSpace<U, Space>) -> Self::Output {
+        InSpace::new(self.value + other.value)
+    }
+}
+
+impl<T: Sub<U>, U, Space> Sub<InSpace<U, Space>> for InSpace<T, Space> {
+    type Output = InSpace<T::Output, Space>;
+
+    #[inline]
+    fn sub(self, other: InSpace<U, Space>) -> Self::Output {
+        InSpace::new(self.value - other.value)
+    }
+}
+
+impl<T: AddAssign<U>, U, Space> AddAssign<InSpace<U, Space>> for InSpace<T, Space> {
+    #[inline]
+    fn add_assign(&mut self, other: InSpace<U, Space>) {
+        self.value += other.value;
+    }
+}
+
+impl<T: SubAssign<U>, U, Space> SubAssign<InSpace<U, Space>> for InSpace<T, Space> {
+    #[inline]
+    fn sub_assign(&mut self, other: InSpace<U, Space>) {
+        self.value -= other.value;
+    }
+}
+
+impl<T: Neg, Space> Neg for InSpace<T, Space> {
+    type Output = InSpace<T::Output, Space>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        InSpace::new(-self.value)
+    }
+}
+
+impl<T: Mul<S>, S: BaseNum, Space> Mul<S> for InSpace<T, Space> {
+    type Output = InSpace<T::Output, Space>;
+
+    #[inline]
+    fn mul(self, scalar: S) -> Self::Output {
+        InSpace::new(self.value * scalar)
+    }
+}
+
+impl<T: Div<S>, S: BaseNum, Space> Div<S> for InSpace<T, Space> {
+    type Output = InSpace<T::Output, Space>;
+
+    #[inline]
+    fn div(self, scalar: S) -> Self::Output {
+        InSpace::new(self.value / scalar)
+    }
+}
+
+/// A transform, such as a `Matrix4` or a `Decomposed`, of points of type `P`
+/// from the coordinate space `Src` to the coordinate space `Dst`.
+///
+/// The point type is part of the type because transforms like `Matrix3` can
+/// transform both `Point2` and `Point3`.
+#[repr(transparent)]
+pub struct SpaceTransform<T, P, Src, Dst> {
+    transform: T,
+    spaces: PhantomData<fn(P, Src) -> Dst>,
+}
+
+impl<T, P, Src, Dst> SpaceTransform<T, P, Src, Dst> {
+    /// Tag a transform with the spaces it transforms from and to.
+    #[inline]
+    pub fn new(transform: T) -> SpaceTransform<T, P, Src, Dst> {
+        SpaceTransform {
+            transform: transform,
+            spaces: PhantomData,
+        }
+    }
+
+    /// The plain transform.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.transform
+    }
+}
+
+impl<T: Transform<P>, P: EuclideanSpace, Src, Dst> SpaceTransform<T, P, Src, Dst> {
+    /// Transform a point from `Src` to `Dst`.
+    #[inline]
+    pub fn transform_point(&self, point: InSpace<P, Src>) -> InSpace<P, Dst> {
+        InSpace::new(self.transform.transform_point(point.value))
+    }
+
+    /// Transform a vector from `Src` to `Dst`.
+    #[inline]
+    pub fn transform_vector(&self, vector: InSpace<P::Diff, Src>) -> InSpace<P::Diff, Dst> {
+        InSpace::new(self.transform.transform_vector(vector.value))
+    }
+
+    /// Combine this transform with a transform into `Src`, which is applied
+    /// first, yielding a transform from the source space of `other` to `Dst`.
+    #[inline]
+    pub fn concat<Prev>(
+        &self,
+        other: &SpaceTransform<T, P, Prev, Src>,
+    ) -> SpaceTransform<T, P, Prev, Dst> {
+        SpaceTransform::new(self.transform.concat(&other.transform))
+    }
+
+    /// The transform from `Dst` back to `Src`.
+    #[inline]
+    pub fn inverse_transform(&self) -> Option<SpaceTransform<T, P, Dst, Src>> {
+        self.transform.inverse_transform().map(SpaceTransform::new)
+    }
+}
+
+impl<T: Copy, P, Src, Dst> Copy for SpaceTransform<T, P, Src, Dst> {}
+
+impl<T: Clone, P, Src, Dst> Clone for SpaceTransform<T, P, Src, Dst> {
+    #[inline]
+    fn clone(&self) -> SpaceTransform<T, P, Src, Dst> {
+        SpaceTransform::new(self.transform.clone())
+    }
+}
+
+impl<T: PartialEq, P, Src, Dst> PartialEq for SpaceTransform<T, P, Src, Dst> {
+    #[inline]
+    fn eq(&self, other: &SpaceTransform<T, P, Src, Dst>) -> bool {
+        self.transform == other.transform
+    }
+}
+
+impl<T: fmt::Debug, P, Src, Dst> fmt::Debug for SpaceTransform<T, P, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.transform.fmt(f)
+    }
+}
@@ -110,6 +110,291 @@ pub trait ElementWise<Rhs = Self> {
     fn rem_assign_element_wise(&mut self, rhs: Rhs);
 }
 
+/// Component-wise versions of the scalar math functions, mirroring the
+/// built-in functions of GLSL.
+///
+/// ```rust
+/// use cgmath::prelude::*;
+/// use cgmath::Vector3;
+///
+/// let v = Vector3::new(-1.5, 0.25, 2.0);
+/// assert_eq!(v.abs(), Vector3::new(1.5, 0.25, 2.0));
+/// assert_eq!(v.floor(), Vector3::new(-2.0, 0.0, 2.0));
+/// assert_eq!(v.max_element(), 2.0);
+/// assert_eq!(v.argmax(), 2);
+/// ```
+///
+/// This is implemented by the vector and point types, but is not implied by
+/// `VectorSpace`, so generic code has to ask for it:
+///
+/// ```rust
+/// use cgmath::prelude::*;
+/// use cgmath::{Vector2, Vector4};
+///
+/// fn saturate<V: VectorSpace<Scalar = f32> + ComponentWise<Element = f32>>(v: V) -> V {
+///     v.clamp(V::zero(), V::from_value(1.0))
+/// }
+///
+/// assert_eq!(saturate(Vector2::new(-0.5, 0.5)), Vector2::new(0.0, 0.5));
+/// assert_eq!(saturate(Vector4::from_value(2.0)), Vector4::from_value(1.0));
+/// ```
+pub trait ComponentWise: Array + Sized {
+    /// The absolute value of each component.
+    #[inline]
+    fn abs(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::abs)
+    }
+
+    /// The sign of each component, as `1` or `-1`, like `Float::signum`.
+    #[inline]
+    fn signum(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::signum)
+    }
+
+    /// The largest integer less than or equal to each component.
+    #[inline]
+    fn floor(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::floor)
+    }
+
+    /// The smallest integer greater than or equal to each component.
+    #[inline]
+    fn ceil(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::ceil)
+    }
+
+    /// The nearest integer to each component, rounding half-way cases away
+    /// from zero.
+    #[inline]
+    fn round(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::round)
+    }
+
+    /// The integer part of each component.
+    #[inline]
+    fn trunc(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::trunc)
+    }
+
+    /// The fractional part of each component, as `x - floor(x)` like in GLSL,
+    /// which is always positive.
+    #[inline]
+    fn fract(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, |x| x - x.floor())
+    }
+
+    /// Raise each component to the power `n`.
+    #[inline]
+    fn powf(self, n: Self::Element) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, |x| x.powf(n))
+    }
+
+    /// The exponential of each component.
+    #[inline]
+    fn exp(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::exp)
+    }
+
+    /// The square root of each component.
+    #[inline]
+    fn sqrt(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::sqrt)
+    }
+
+    /// The reciprocal of each component.
+    #[inline]
+    fn recip(self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        map_components(self, Float::recip)
+    }
+
+    /// The smaller of each pair of components.
+    #[inline]
+    fn min(self, other: Self) -> Self
+    where
+        Self::Element: BaseNum,
+    {
+        zip_components(self, other, |a, b| if b < a { b } else { a })
+    }
+
+    /// The larger of each pair of components.
+    #[inline]
+    fn max(self, other: Self) -> Self
+    where
+        Self::Element: BaseNum,
+    {
+        zip_components(self, other, |a, b| if b > a { b } else { a })
+    }
+
+    /// Constrain each component to lie between the matching components of
+    /// `min` and `max`.
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self
+    where
+        Self::Element: BaseNum,
+    {
+        let mut result = self;
+        for i in 0..Self::len() {
+            result[i] = clamp_component(result[i], min[i], max[i]);
+        }
+        result
+    }
+
+    /// Linearly interpolate each component towards `other`, using the matching
+    /// component of `amount`. Use `VectorSpace::lerp` for a single amount.
+    #[inline]
+    fn mix(self, other: Self, amount: Self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        let mut result = self;
+        for i in 0..Self::len() {
+            result[i] = result[i] + (other[i] - result[i]) * amount[i];
+        }
+        result
+    }
+
+    /// Zero for each component that is less than the matching component of
+    /// `edge`, and one otherwise.
+    #[inline]
+    fn step(self, edge: Self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        zip_components(self, edge, |x, edge| {
+            if x < edge {
+                Self::Element::zero()
+            } else {
+                Self::Element::one()
+            }
+        })
+    }
+
+    /// Smooth Hermite interpolation between zero and one for each component,
+    /// as it goes from the matching component of `edge0` to that of `edge1`.
+    #[inline]
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self
+    where
+        Self::Element: BaseFloat,
+    {
+        let two = Self::Element::one() + Self::Element::one();
+        let three = two + Self::Element::one();
+        let mut result = self;
+        for i in 0..Self::len() {
+            let t = (result[i] - edge0[i]) / (edge1[i] - edge0[i]);
+            let t = clamp_component(t, Self::Element::zero(), Self::Element::one());
+            result[i] = t * t * (three - two * t);
+        }
+        result
+    }
+
+    /// The smallest component.
+    #[inline]
+    fn min_element(self) -> Self::Element
+    where
+        Self::Element: BaseNum,
+    {
+        let i = self.argmin();
+        self[i]
+    }
+
+    /// The largest component.
+    #[inline]
+    fn max_element(self) -> Self::Element
+    where
+        Self::Element: BaseNum,
+    {
+        let i = self.argmax();
+        self[i]
+    }
+
+    /// The index of the smallest component, or the first of them if there
+    /// are several.
+    #[inline]
+    fn argmin(&self) -> usize
+    where
+        Self::Element: BaseNum,
+    {
+        (1..Self::len()).fold(0, |min, i| if self[i] < self[min] { i } else { min })
+    }
+
+    /// The index of the largest component, or the first of them if there are
+    /// several.
+    #[inline]
+    fn argmax(&self) -> usize
+    where
+        Self::Element: BaseNum,
+    {
+        (1..Self::len()).fold(0, |max, i| if self[i] > self[max] { i } else { max })
+    }
+}
+
+#[inline]
+fn map_components<A: Array, F>(mut a: A, mut f: F) -> A
+where
+    F: FnMut(A::Element) -> A::Element,
+{
+    for i in 0..A::len() {
+        a[i] = f(a[i]);
+    }
+    a
+}
+
+/// Constrain `x` to lie between `min` and `max`.
+#[inline]
+fn clamp_component<S: BaseNum>(x: S, min: S, max: S) -> S {
+    if x < min {
+        min
+    } else if x > max {
+        max
+    } else {
+        x
+    }
+}
+
+#[inline]
+fn zip_components<A: Array, F>(mut a: A, b: A, mut f: F) -> A
+where
+    F: FnMut(A::Element, A::Element) -> A::Element,
+{
+    for i in 0..A::len() {
+        a[i] = f(a[i], b[i]);
+    }
+    a
+}
+
 /// Vectors that can be [added](http://mathworld.wolfram.com/VectorAddition.html)
 /// together and [multiplied](https://en.wikipedia.org/wiki/Scalar_multiplication)
 /// by scalars.
@@ -254,6 +539,56 @@ where
         other * (self.dot(other) / other.magnitude2())
     }
 
+    /// Returns the component of the vector perpendicular to `other`, which
+    /// is the remainder after subtracting the projection onto `other`.
+    #[inline]
+    fn reject_from(self, other: Self) -> Self {
+        self - self.project_on(other)
+    }
+
+    /// Projects the vector onto the plane through the origin with the given
+    /// unit `normal`.
+    #[inline]
+    fn project_on_plane(self, normal: Self) -> Self {
+        self - normal * self.dot(normal)
+    }
+
+    /// Reflects the vector about the plane with the given unit `normal`, like
+    /// GLSL's `reflect`.
+    #[inline]
+    fn reflect(self, normal: Self) -> Self {
+        let d = self.dot(normal);
+        self - normal * (d + d)
+    }
+
+    /// Refracts the vector through a surface with the given unit `normal`,
+    /// like GLSL's `refract`. Both the vector and the normal should be
+    /// normalized, and `eta` is the ratio of the indices of refraction.
+    ///
+    /// Returns `None` on total internal reflection.
+    fn refract(self, normal: Self, eta: Self::Scalar) -> Option<Self> where Self::Scalar: Float {
+        let d = self.dot(normal);
+        let k = Self::Scalar::one() - eta * eta * (Self::Scalar::one() - d * d);
+        if k < Self::Scalar::zero() {
+            return None;
+        }
+        Some(self * eta - normal * (eta * d + Float::sqrt(k)))
+    }
+
+    /// Returns the vector if it faces away from `incident` according to
+    /// `reference`, and its negation otherwise, like GLSL's `faceforward`.
+    ///
+    /// This is usually used to flip a normal `N` towards the viewer, as in
+    /// `n.faceforward(incident, n)`.
+    #[inline]
+    fn faceforward(self, incident: Self, reference: Self) -> Self {
+        if reference.dot(incident) < Self::Scalar::zero() {
+            self
+        } else {
+            Self::zero() - self
+        }
+    }
+
     /// The distance from the tail to the tip of the vector.
     #[inline]
     fn magnitude(self) -> Self::Scalar where Self::Scalar: Float {
@@ -554,6 +889,31 @@ where
     /// Test if this matrix is symmetric. That is, it is equal to its
     /// transpose.
     fn is_symmetric(&self) -> bool;
+
+    /// The [matrix exponential], computed using a Padé approximant with
+    /// scaling and squaring.
+    ///
+    /// [matrix exponential]: https://en.wikipedia.org/wiki/Matrix_exponential
+    fn exp(&self) -> Self;
+
+    /// The principal [matrix logarithm], which is the inverse of `exp`,
+    /// computed using inverse scaling and squaring.
+    ///
+    /// Returns `None` if the matrix has no real principal logarithm, which is
+    /// the case when it is singular or has negative real eigenvalues.
+    ///
+    /// [matrix logarithm]: https://en.wikipedia.org/wiki/Logarithm_of_a_matrix
+    fn log(&self) -> Option<Self>;
+
+    /// Raise the matrix to a fractional power, computed as
+    /// `(self.log() * t).exp()`. This can be used to interpolate between
+    /// transformations.
+    ///
+    /// Returns `None` if the matrix has no real principal logarithm.
+    #[inline]
+    fn powf(&self, t: Self::Scalar) -> Option<Self> {
+        self.log().map(|log| (log * t).exp())
+    }
 }
 
 /// Angles and their associated trigonometric functions.
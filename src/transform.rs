@@ -13,14 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use num_traits::cast;
+use std::fmt;
+use std::mem;
+
 use structure::*;
 
 use approx;
-use matrix::{Matrix2, Matrix3, Matrix4};
+use matrix::{Matrix2, Matrix3, Matrix3x4, Matrix4, Matrix4x3};
 use num::{BaseFloat, BaseNum};
 use point::{Point2, Point3};
 use rotation::*;
-use vector::{Vector2, Vector3};
+use vector::{Vector2, Vector3, Vector4};
 
 /// A trait representing an [affine
 /// transformation](https://en.wikipedia.org/wiki/Affine_transformation) that
@@ -159,6 +163,60 @@ impl<S: BaseFloat, R: Rotation3<S>> From<Decomposed<Vector3<S>, R>> for Matrix4<
     }
 }
 
+impl<S: BaseFloat, R: Rotation3<S>> Decomposed<Vector3<S>, R> {
+    /// Create a right-handed view transform, for a camera at `eye` looking in
+    /// the direction `dir`, using `up` for orientation.
+    pub fn look_to_rh(
+        eye: Point3<S>,
+        dir: Vector3<S>,
+        up: Vector3<S>,
+    ) -> Decomposed<Vector3<S>, R> {
+        let rot = R::look_to_rh(dir, up);
+        Decomposed {
+            scale: S::one(),
+            rot: rot,
+            disp: rot.rotate_vector(Point3::origin() - eye),
+        }
+    }
+
+    /// Create a left-handed view transform, for a camera at `eye` looking in
+    /// the direction `dir`, using `up` for orientation.
+    pub fn look_to_lh(
+        eye: Point3<S>,
+        dir: Vector3<S>,
+        up: Vector3<S>,
+    ) -> Decomposed<Vector3<S>, R> {
+        let rot = R::look_to_lh(dir, up);
+        Decomposed {
+            scale: S::one(),
+            rot: rot,
+            disp: rot.rotate_vector(Point3::origin() - eye),
+        }
+    }
+
+    /// Create a right-handed view transform, for a camera at `eye` looking at
+    /// `center`, using `up` for orientation.
+    pub fn look_at_rh(
+        eye: Point3<S>,
+        center: Point3<S>,
+        up: Vector3<S>,
+    ) -> Decomposed<Vector3<S>, R> {
+        Decomposed::look_to_rh(eye, center - eye, up)
+    }
+
+    /// Create a left-handed view transform, for a camera at `eye` looking at
+    /// `center`, using `up` for orientation.
+    ///
+    /// This is the same as `Transform::look_at`.
+    pub fn look_at_lh(
+        eye: Point3<S>,
+        center: Point3<S>,
+        up: Vector3<S>,
+    ) -> Decomposed<Vector3<S>, R> {
+        Decomposed::look_to_lh(eye, center - eye, up)
+    }
+}
+
 impl<S: BaseFloat, R: Rotation2<S>> Transform2<S> for Decomposed<Vector2<S>, R> {}
 
 impl<S: BaseFloat, R: Rotation3<S>> Transform3<S> for Decomposed<Vector3<S>, R> {}
@@ -222,6 +280,239 @@ where
     }
 }
 
+/// A compact affine transformation in three dimensions, stored as the top
+/// three rows of a homogeneous `Matrix4`, whose last row is implicitly
+/// `(0, 0, 0, 1)`.
+///
+/// The rows are stored contiguously, giving this type the row-major
+/// `[[S; 4]; 3]` layout that graphics APIs commonly expect for per-instance
+/// transforms. This saves a quarter of the space of a full `Matrix4`.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AffineMatrix3<S> {
+    /// The first row of the matrix.
+    pub x: Vector4<S>,
+    /// The second row of the matrix.
+    pub y: Vector4<S>,
+    /// The third row of the matrix.
+    pub z: Vector4<S>,
+}
+
+impl<S> AffineMatrix3<S> {
+    /// Create a new matrix, providing values for each index in row-major
+    /// order.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        r0c0: S, r0c1: S, r0c2: S, r0c3: S,
+        r1c0: S, r1c1: S, r1c2: S, r1c3: S,
+        r2c0: S, r2c1: S, r2c2: S, r2c3: S,
+    ) -> AffineMatrix3<S> {
+        AffineMatrix3::from_rows(
+            Vector4::new(r0c0, r0c1, r0c2, r0c3),
+            Vector4::new(r1c0, r1c1, r1c2, r1c3),
+            Vector4::new(r2c0, r2c1, r2c2, r2c3),
+        )
+    }
+
+    /// Create a new matrix, providing rows.
+    #[inline]
+    pub const fn from_rows(r0: Vector4<S>, r1: Vector4<S>, r2: Vector4<S>) -> AffineMatrix3<S> {
+        AffineMatrix3 {
+            x: r0,
+            y: r1,
+            z: r2,
+        }
+    }
+}
+
+impl<S: BaseFloat> AffineMatrix3<S> {
+    /// Create an affine transformation from a linear part and a translation.
+    #[inline]
+    pub fn from_linear_translation(
+        linear: Matrix3<S>,
+        translation: Vector3<S>,
+    ) -> AffineMatrix3<S> {
+        AffineMatrix3::from_rows(
+            linear.row(0).extend(translation.x),
+            linear.row(1).extend(translation.y),
+            linear.row(2).extend(translation.z),
+        )
+    }
+
+    /// Convert a homogeneous transformation matrix, returning `None` if its
+    /// last row is not exactly `(0, 0, 0, 1)`.
+    pub fn from_matrix4(m: Matrix4<S>) -> Option<AffineMatrix3<S>> {
+        if m.row(3) == Vector4::unit_w() {
+            Some(AffineMatrix3::from_rows(m.row(0), m.row(1), m.row(2)))
+        } else {
+            None
+        }
+    }
+
+    /// The upper-left 3 x 3 linear part of the transformation.
+    #[inline]
+    pub fn linear(&self) -> Matrix3<S> {
+        Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate()).transpose()
+    }
+
+    /// The translation part of the transformation.
+    #[inline]
+    pub fn translation(&self) -> Vector3<S> {
+        Vector3::new(self.x.w, self.y.w, self.z.w)
+    }
+
+    /// Are all entries in the matrix finite.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+}
+
+impl<S: BaseFloat> Transform<Point3<S>> for AffineMatrix3<S> {
+    fn one() -> AffineMatrix3<S> {
+        AffineMatrix3::from_linear_translation(Matrix3::identity(), Vector3::zero())
+    }
+
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> AffineMatrix3<S> {
+        let m = Matrix4::look_at(eye, center, up);
+        AffineMatrix3::from_rows(m.row(0), m.row(1), m.row(2))
+    }
+
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        let v = vec.extend(S::zero());
+        Vector3::new(self.x.dot(v), self.y.dot(v), self.z.dot(v))
+    }
+
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        let v = point.to_homogeneous();
+        Point3::new(self.x.dot(v), self.y.dot(v), self.z.dot(v))
+    }
+
+    fn concat(&self, other: &AffineMatrix3<S>) -> AffineMatrix3<S> {
+        AffineMatrix3::from_linear_translation(
+            self.linear() * other.linear(),
+            self.transform_vector(other.translation()) + self.translation(),
+        )
+    }
+
+    fn inverse_transform(&self) -> Option<AffineMatrix3<S>> {
+        // Only the 3 x 3 linear part needs a full inversion, the translation
+        // is then undone in the inverted basis.
+        self.linear().invert().map(|linear| {
+            let translation = -(linear * self.translation());
+            AffineMatrix3::from_linear_translation(linear, translation)
+        })
+    }
+}
+
+impl<S: BaseFloat> Transform3<S> for AffineMatrix3<S> {}
+
+impl<S: BaseFloat> From<AffineMatrix3<S>> for Matrix4<S> {
+    /// Expand to a homogeneous transformation matrix, with a last row of
+    /// `(0, 0, 0, 1)`.
+    fn from(m: AffineMatrix3<S>) -> Matrix4<S> {
+        Matrix4::from_cols(m.x, m.y, m.z, Vector4::unit_w()).transpose()
+    }
+}
+
+impl<S: BaseFloat> From<AffineMatrix3<S>> for Matrix4x3<S> {
+    /// Convert to the equivalent column-major matrix with 4 columns and 3 rows.
+    fn from(m: AffineMatrix3<S>) -> Matrix4x3<S> {
+        Matrix3x4::from_cols(m.x, m.y, m.z).transpose()
+    }
+}
+
+impl<S: BaseFloat> From<Matrix4x3<S>> for AffineMatrix3<S> {
+    fn from(m: Matrix4x3<S>) -> AffineMatrix3<S> {
+        AffineMatrix3::from_rows(m.row(0), m.row(1), m.row(2))
+    }
+}
+
+impl<S> Into<[[S; 4]; 3]> for AffineMatrix3<S> {
+    #[inline]
+    fn into(self) -> [[S; 4]; 3] {
+        match self {
+            AffineMatrix3 { x, y, z } => [x.into(), y.into(), z.into()],
+        }
+    }
+}
+
+impl<S> AsRef<[[S; 4]; 3]> for AffineMatrix3<S> {
+    #[inline]
+    fn as_ref(&self) -> &[[S; 4]; 3] {
+        unsafe { mem::transmute(self) }
+    }
+}
+
+impl<S> AsMut<[[S; 4]; 3]> for AffineMatrix3<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [[S; 4]; 3] {
+        unsafe { mem::transmute(self) }
+    }
+}
+
+impl<S: Copy> From<[[S; 4]; 3]> for AffineMatrix3<S> {
+    #[inline]
+    fn from(m: [[S; 4]; 3]) -> AffineMatrix3<S> {
+        AffineMatrix3::from_rows(m[0].into(), m[1].into(), m[2].into())
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for AffineMatrix3<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        cast(1.0e-6f64).unwrap()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector4::abs_diff_eq(&self.x, &other.x, epsilon)
+            && Vector4::abs_diff_eq(&self.y, &other.y, epsilon)
+            && Vector4::abs_diff_eq(&self.z, &other.z, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for AffineMatrix3<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector4::relative_eq(&self.x, &other.x, epsilon, max_relative)
+            && Vector4::relative_eq(&self.y, &other.y, epsilon, max_relative)
+            && Vector4::relative_eq(&self.z, &other.z, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for AffineMatrix3<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector4::ulps_eq(&self.x, &other.x, epsilon, max_ulps)
+            && Vector4::ulps_eq(&self.y, &other.y, epsilon, max_ulps)
+            && Vector4::ulps_eq(&self.z, &other.z, epsilon, max_ulps)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for AffineMatrix3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AffineMatrix3 ")?;
+        <[[S; 4]; 3] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
 #[cfg(feature = "serde")]
 #[doc(hidden)]
 mod serde_ser {
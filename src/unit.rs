@@ -0,0 +1,162 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::{cast, Float};
+use std::ops::{Deref, Neg};
+
+use structure::*;
+
+use angle::Rad;
+use approx::AbsDiffEq;
+use num::{BaseFloat, BaseNum};
+use quaternion::Quaternion;
+use rotation::Rotation3;
+use vector::Vector3;
+
+/// A vector or quaternion with a magnitude of `1`.
+///
+/// Apart from `new_unchecked`, a `Unit` can only be constructed by checking
+/// that the value is already normalized, or by normalizing it, so functions
+/// taking a `Unit` do not have to trust their callers to normalize it. The
+/// wrapped value can be read through `Deref`, but not modified. Deserializing
+/// checks the magnitude like `Unit::new`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Unit<T> {
+    value: T,
+}
+
+impl<T> Unit<T> {
+    /// Wrap a value without checking its magnitude.
+    ///
+    /// The value **must be normalized**, otherwise functions taking the
+    /// `Unit` will give wrong results.
+    #[inline]
+    pub const fn new_unchecked(value: T) -> Unit<T> {
+        Unit { value: value }
+    }
+
+    /// The wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: InnerSpace> Unit<T>
+where
+    T::Scalar: BaseFloat,
+{
+    /// Wrap a value that is already normalized, or return `None` if its
+    /// magnitude is not `1`, allowing for a few units of rounding error.
+    pub fn new(value: T) -> Option<Unit<T>> {
+        let tolerance = T::Scalar::default_epsilon() * cast(8).unwrap();
+        if AbsDiffEq::abs_diff_eq(&value.magnitude2(), &T::Scalar::one(), tolerance) {
+            Some(Unit::new_unchecked(value))
+        } else {
+            None
+        }
+    }
+
+    /// Normalize a value, or return `None` if it is too close to zero, or not
+    /// finite, to have a direction.
+    pub fn try_normalize(value: T) -> Option<Unit<T>> {
+        let magnitude = value.magnitude();
+        if magnitude > T::Scalar::zero() && Float::is_finite(magnitude) {
+            Some(Unit::new_unchecked(value / magnitude))
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: BaseNum> Unit<Vector3<S>> {
+    /// A unit vector in the `x` direction.
+    #[inline]
+    pub fn unit_x() -> Unit<Vector3<S>> {
+        Unit::new_unchecked(Vector3::unit_x())
+    }
+
+    /// A unit vector in the `y` direction.
+    #[inline]
+    pub fn unit_y() -> Unit<Vector3<S>> {
+        Unit::new_unchecked(Vector3::unit_y())
+    }
+
+    /// A unit vector in the `z` direction.
+    #[inline]
+    pub fn unit_z() -> Unit<Vector3<S>> {
+        Unit::new_unchecked(Vector3::unit_z())
+    }
+}
+
+impl<S: BaseFloat> Unit<Quaternion<S>> {
+    /// Create a rotation using an angle around a given axis.
+    #[inline]
+    pub fn from_axis_angle<A: Into<Rad<S>>>(
+        axis: Unit<Vector3<S>>,
+        angle: A,
+    ) -> Unit<Quaternion<S>> {
+        Unit::new_unchecked(Quaternion::from_axis_angle(axis, angle))
+    }
+}
+
+impl<T> Deref for Unit<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsRef<T> for Unit<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Unit<T> {
+    type Output = Unit<T>;
+
+    #[inline]
+    fn neg(self) -> Unit<T> {
+        Unit::new_unchecked(-self.value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T> ::serde::Deserialize<'a> for Unit<T>
+where
+    T: ::serde::Deserialize<'a> + InnerSpace,
+    T::Scalar: BaseFloat,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Unit<T>, D::Error>
+    where
+        D: ::serde::Deserializer<'a>,
+    {
+        // The same representation as the derived `Serialize`.
+        #[derive(Deserialize)]
+        #[serde(rename = "Unit")]
+        struct Unchecked<T> {
+            value: T,
+        }
+
+        let unchecked = Unchecked::deserialize(deserializer)?;
+        Unit::new(unchecked.value)
+            .ok_or_else(|| ::serde::de::Error::custom("expected a magnitude of 1"))
+    }
+}
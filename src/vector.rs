@@ -13,7 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use num_traits::{Float, Bounded, NumCast};
+use num_traits::{
+    cast, Bounded, Float, NumCast, Saturating, WrappingAdd, WrappingMul, WrappingSub,
+};
 #[cfg(feature = "rand")]
 use rand::{
     distributions::{Distribution, Standard},
@@ -28,7 +30,7 @@ use structure::*;
 
 use angle::Rad;
 use approx;
-use num::{BaseFloat, BaseNum};
+use num::{BaseFloat, BaseInt, BaseNum};
 
 #[cfg(feature = "mint")]
 use mint;
@@ -209,8 +211,7 @@ macro_rules! impl_vector {
         impl<S: Neg<Output = S>> Neg for $VectorN<S> {
             type Output = $VectorN<S>;
 
-            #[inline]
-            default_fn!( neg(self) -> $VectorN<S> { $VectorN::new($(-self.$field),+) } );
+            default_fn!(#[inline] neg(self) -> $VectorN<S> { $VectorN::new($(-self.$field),+) } );
         }
 
         impl<S: BaseFloat> approx::AbsDiffEq for $VectorN<S> {
@@ -308,31 +309,192 @@ macro_rules! impl_vector {
             fn rem_assign(&mut self, scalar) { $(self.$field %= scalar);+ }
         });
 
+        impl_operator!(<S: BaseInt> Not for $VectorN<S> {
+            fn not(vector) -> $VectorN<S> { $VectorN::new($(!vector.$field),+) }
+        });
+
+        impl_operator!(<S: BaseInt> BitAnd<$VectorN<S> > for $VectorN<S> {
+            fn bitand(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field & rhs.$field),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> BitAndAssign<$VectorN<S> > for $VectorN<S> {
+            fn bitand_assign(&mut self, other) { $(self.$field &= other.$field);+ }
+        });
+
+        impl_operator!(<S: BaseInt> BitOr<$VectorN<S> > for $VectorN<S> {
+            fn bitor(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field | rhs.$field),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> BitOrAssign<$VectorN<S> > for $VectorN<S> {
+            fn bitor_assign(&mut self, other) { $(self.$field |= other.$field);+ }
+        });
+
+        impl_operator!(<S: BaseInt> BitXor<$VectorN<S> > for $VectorN<S> {
+            fn bitxor(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field ^ rhs.$field),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> BitXorAssign<$VectorN<S> > for $VectorN<S> {
+            fn bitxor_assign(&mut self, other) { $(self.$field ^= other.$field);+ }
+        });
+
+        impl_operator!(<S: BaseInt> BitAnd<S> for $VectorN<S> {
+            fn bitand(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field & scalar),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> BitAndAssign<S> for $VectorN<S> {
+            fn bitand_assign(&mut self, scalar) { $(self.$field &= scalar);+ }
+        });
+
+        impl_operator!(<S: BaseInt> BitOr<S> for $VectorN<S> {
+            fn bitor(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field | scalar),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> BitOrAssign<S> for $VectorN<S> {
+            fn bitor_assign(&mut self, scalar) { $(self.$field |= scalar);+ }
+        });
+
+        impl_operator!(<S: BaseInt> BitXor<S> for $VectorN<S> {
+            fn bitxor(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field ^ scalar),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> BitXorAssign<S> for $VectorN<S> {
+            fn bitxor_assign(&mut self, scalar) { $(self.$field ^= scalar);+ }
+        });
+
+        impl_operator!(<S: BaseInt> Shl<usize> for $VectorN<S> {
+            fn shl(vector, shift) -> $VectorN<S> { $VectorN::new($(vector.$field << shift),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> ShlAssign<usize> for $VectorN<S> {
+            fn shl_assign(&mut self, shift) { $(self.$field <<= shift);+ }
+        });
+
+        impl_operator!(<S: BaseInt> Shr<usize> for $VectorN<S> {
+            fn shr(vector, shift) -> $VectorN<S> { $VectorN::new($(vector.$field >> shift),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> ShrAssign<usize> for $VectorN<S> {
+            fn shr_assign(&mut self, shift) { $(self.$field >>= shift);+ }
+        });
+
+        impl_operator!(<S: BaseInt> Shl<$VectorN<usize> > for $VectorN<S> {
+            fn shl(vector, shift) -> $VectorN<S> { $VectorN::new($(vector.$field << shift.$field),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> ShlAssign<$VectorN<usize> > for $VectorN<S> {
+            fn shl_assign(&mut self, shift) { $(self.$field <<= shift.$field);+ }
+        });
+
+        impl_operator!(<S: BaseInt> Shr<$VectorN<usize> > for $VectorN<S> {
+            fn shr(vector, shift) -> $VectorN<S> { $VectorN::new($(vector.$field >> shift.$field),+) }
+        });
+        impl_assignment_operator!(<S: BaseInt> ShrAssign<$VectorN<usize> > for $VectorN<S> {
+            fn shr_assign(&mut self, shift) { $(self.$field >>= shift.$field);+ }
+        });
+
+        impl<S: BaseInt> $VectorN<S> {
+            /// Component-wise addition that wraps around at the bounds of the
+            /// scalar type.
+            #[inline]
+            pub fn wrapping_add(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(WrappingAdd::wrapping_add(&self.$field, &other.$field)),+)
+            }
+
+            /// Component-wise subtraction that wraps around at the bounds of
+            /// the scalar type.
+            #[inline]
+            pub fn wrapping_sub(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(WrappingSub::wrapping_sub(&self.$field, &other.$field)),+)
+            }
+
+            /// Component-wise multiplication that wraps around at the bounds
+            /// of the scalar type.
+            #[inline]
+            pub fn wrapping_mul(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(WrappingMul::wrapping_mul(&self.$field, &other.$field)),+)
+            }
+
+            /// Component-wise addition that saturates at the bounds of the
+            /// scalar type.
+            #[inline]
+            pub fn saturating_add(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(Saturating::saturating_add(self.$field, other.$field)),+)
+            }
+
+            /// Component-wise subtraction that saturates at the bounds of the
+            /// scalar type.
+            #[inline]
+            pub fn saturating_sub(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(Saturating::saturating_sub(self.$field, other.$field)),+)
+            }
+
+            /// Component-wise conversion to floating point, rounding to the
+            /// nearest representable value like `as`.
+            #[inline]
+            pub fn to_float<T: BaseFloat>(self) -> $VectorN<T> {
+                $VectorN::new($(int_to_float(self.$field)),+)
+            }
+        }
+
+        impl<S: BaseFloat> $VectorN<S> {
+            /// Component-wise conversion to integers, rounding half-way cases
+            /// away from zero.
+            ///
+            /// Like `as`, values outside the range of `T` saturate to its
+            /// bounds, and NaN converts to zero.
+            #[inline]
+            pub fn round_to_int<T: BaseInt>(self) -> $VectorN<T> {
+                $VectorN::new($(float_to_int(self.$field.round())),+)
+            }
+
+            /// Component-wise conversion to integers, rounding towards
+            /// negative infinity.
+            ///
+            /// Like `as`, values outside the range of `T` saturate to its
+            /// bounds, and NaN converts to zero.
+            #[inline]
+            pub fn floor_to_int<T: BaseInt>(self) -> $VectorN<T> {
+                $VectorN::new($(float_to_int(self.$field.floor())),+)
+            }
+
+            /// Component-wise conversion to integers, rounding towards
+            /// positive infinity.
+            ///
+            /// Like `as`, values outside the range of `T` saturate to its
+            /// bounds, and NaN converts to zero.
+            #[inline]
+            pub fn ceil_to_int<T: BaseInt>(self) -> $VectorN<T> {
+                $VectorN::new($(float_to_int(self.$field.ceil())),+)
+            }
+
+            /// Component-wise conversion to integers, rounding towards zero.
+            ///
+            /// Like `as`, values outside the range of `T` saturate to its
+            /// bounds, and NaN converts to zero.
+            #[inline]
+            pub fn trunc_to_int<T: BaseInt>(self) -> $VectorN<T> {
+                $VectorN::new($(float_to_int(self.$field.trunc())),+)
+            }
+        }
+
+        impl<S: BaseNum> ComponentWise for $VectorN<S> {}
+
         impl<S: BaseNum> ElementWise for $VectorN<S> {
-            #[inline] default_fn!( add_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field + rhs.$field),+) } );
-            #[inline] default_fn!( sub_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field - rhs.$field),+) } );
-            #[inline] default_fn!( mul_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field * rhs.$field),+) } );
-            #[inline] default_fn!( div_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field / rhs.$field),+) } );
+            default_fn!(#[inline] add_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field + rhs.$field),+) } );
+            default_fn!(#[inline] sub_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field - rhs.$field),+) } );
+            default_fn!(#[inline] mul_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field * rhs.$field),+) } );
+            default_fn!(#[inline] div_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field / rhs.$field),+) } );
             #[inline] fn rem_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field % rhs.$field),+) }
 
-            #[inline] default_fn!( add_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field += rhs.$field);+ } );
-            #[inline] default_fn!( sub_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field -= rhs.$field);+ } );
-            #[inline] default_fn!( mul_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field *= rhs.$field);+ } );
-            #[inline] default_fn!( div_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field /= rhs.$field);+ } );
+            default_fn!(#[inline] add_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field += rhs.$field);+ } );
+            default_fn!(#[inline] sub_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field -= rhs.$field);+ } );
+            default_fn!(#[inline] mul_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field *= rhs.$field);+ } );
+            default_fn!(#[inline] div_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field /= rhs.$field);+ } );
             #[inline] fn rem_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field %= rhs.$field);+ }
         }
 
         impl<S: BaseNum> ElementWise<S> for $VectorN<S> {
-            #[inline] default_fn!( add_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field + rhs),+) } );
-            #[inline] default_fn!( sub_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field - rhs),+) } );
-            #[inline] default_fn!( mul_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field * rhs),+) } );
-            #[inline] default_fn!( div_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field / rhs),+) } );
+            default_fn!(#[inline] add_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field + rhs),+) } );
+            default_fn!(#[inline] sub_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field - rhs),+) } );
+            default_fn!(#[inline] mul_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field * rhs),+) } );
+            default_fn!(#[inline] div_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field / rhs),+) } );
             #[inline] fn rem_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field % rhs),+) }
 
-            #[inline] default_fn!( add_assign_element_wise(&mut self, rhs: S) { $(self.$field += rhs);+ } );
-            #[inline] default_fn!( sub_assign_element_wise(&mut self, rhs: S) { $(self.$field -= rhs);+ } );
-            #[inline] default_fn!( mul_assign_element_wise(&mut self, rhs: S) { $(self.$field *= rhs);+ } );
-            #[inline] default_fn!( div_assign_element_wise(&mut self, rhs: S) { $(self.$field /= rhs);+ } );
+            default_fn!(#[inline] add_assign_element_wise(&mut self, rhs: S) { $(self.$field += rhs);+ } );
+            default_fn!(#[inline] sub_assign_element_wise(&mut self, rhs: S) { $(self.$field -= rhs);+ } );
+            default_fn!(#[inline] mul_assign_element_wise(&mut self, rhs: S) { $(self.$field *= rhs);+ } );
+            default_fn!(#[inline] div_assign_element_wise(&mut self, rhs: S) { $(self.$field /= rhs);+ } );
             #[inline] fn rem_assign_element_wise(&mut self, rhs: S) { $(self.$field %= rhs);+ }
         }
 
@@ -357,6 +519,21 @@ macro_rules! impl_vector {
     }
 }
 
+/// Converts an integral float to an integer, saturating like `as`.
+fn float_to_int<S: BaseFloat, T: BaseInt>(value: S) -> T {
+    match NumCast::from(value) {
+        Some(value) => value,
+        None if value.is_nan() => T::zero(),
+        None if value > S::zero() => T::max_value(),
+        None => T::min_value(),
+    }
+}
+
+/// Converts an integer to a float, which cannot fail for the primitive types.
+fn int_to_float<S: BaseInt, T: BaseFloat>(value: S) -> T {
+    NumCast::from(value).unwrap()
+}
+
 macro_rules! impl_scalar_ops {
     ($VectorN:ident<$S:ident> { $($field:ident),+ }) => {
         impl_operator!(Mul<$VectorN<$S>> for $S {
@@ -425,6 +602,37 @@ impl<S: BaseNum> Vector2<S> {
     impl_swizzle_functions!(Vector1, Vector2, Vector3, Vector4, S, xy);
 }
 
+impl<S: BaseFloat> Vector2<S> {
+    /// Do a normalized linear interpolation with `other`, by `amount`.
+    #[inline]
+    pub fn nlerp(self, other: Vector2<S>, amount: S) -> Vector2<S> {
+        self.lerp(other, amount).normalize()
+    }
+
+    /// Spherical linear interpolation between this unit vector and `other`,
+    /// by `amount`. The result turns at a constant rate and keeps a unit
+    /// length, unlike `lerp`.
+    ///
+    /// Opposite vectors are interpolated counterclockwise.
+    pub fn slerp(self, other: Vector2<S>, amount: S) -> Vector2<S> {
+        slerp_unit(self, other, amount, || Vector2::new(-self.y, self.x))
+    }
+
+    /// Rotates this unit vector towards the unit vector `target`, by no more
+    /// than `max_angle`. Returns `target` once it is within reach.
+    pub fn rotate_towards(self, target: Vector2<S>, max_angle: Rad<S>) -> Vector2<S> {
+        rotate_towards_unit(self, target, max_angle, || Vector2::new(-self.y, self.x))
+    }
+
+    /// Returns the signed angle from this vector to `other`, in the range
+    /// `(-π, π]`, which is positive when `other` is counterclockwise from
+    /// this vector.
+    #[inline]
+    pub fn signed_angle(self, other: Vector2<S>) -> Rad<S> {
+        Rad::atan2(self.perp_dot(other), self.dot(other))
+    }
+}
+
 impl<S: BaseNum> Vector3<S> {
     /// A unit vector in the `x` direction.
     #[inline]
@@ -470,6 +678,77 @@ impl<S: BaseNum> Vector3<S> {
     impl_swizzle_functions!(Vector1, Vector2, Vector3, Vector4, S, xyz);
 }
 
+impl<S: BaseFloat> Vector3<S> {
+    /// Returns a tangent and a bitangent that form a right-handed orthonormal
+    /// basis together with this unit vector, so that the cross product of the
+    /// tangent and the bitangent is this vector.
+    ///
+    /// This uses the branchless method of [Duff et al.], which is stable for
+    /// all unit vectors, including those pointing along the negative z axis.
+    ///
+    /// [Duff et al.]: http://jcgt.org/published/0006/01/01/
+    pub fn orthonormal_basis(self) -> (Vector3<S>, Vector3<S>) {
+        let sign = self.z.signum();
+        let a = -S::one() / (sign + self.z);
+        let b = self.x * self.y * a;
+        let tangent = Vector3::new(
+            S::one() + sign * self.x * self.x * a,
+            sign * b,
+            -sign * self.x,
+        );
+        let bitangent = Vector3::new(b, sign + self.y * self.y * a, -self.y);
+        (tangent, bitangent)
+    }
+
+    /// Returns a unit vector perpendicular to this vector, which does not need
+    /// to be normalized but must not be zero.
+    pub fn any_orthogonal(self) -> Vector3<S> {
+        // Cross with the z axis, or with the x axis if the vector is closer to
+        // the z axis, so that the result never gets close to zero.
+        let orthogonal = if self.x.abs() > self.z.abs() {
+            Vector3::new(-self.y, self.x, S::zero())
+        } else {
+            Vector3::new(S::zero(), -self.z, self.y)
+        };
+        orthogonal.normalize()
+    }
+
+    /// Do a normalized linear interpolation with `other`, by `amount`.
+    #[inline]
+    pub fn nlerp(self, other: Vector3<S>, amount: S) -> Vector3<S> {
+        self.lerp(other, amount).normalize()
+    }
+
+    /// Spherical linear interpolation between this unit vector and `other`,
+    /// by `amount`. The result turns at a constant rate and keeps a unit
+    /// length, unlike `lerp`.
+    ///
+    /// Opposite vectors are interpolated about an arbitrary perpendicular
+    /// axis.
+    pub fn slerp(self, other: Vector3<S>, amount: S) -> Vector3<S> {
+        slerp_unit(self, other, amount, || self.any_orthogonal())
+    }
+
+    /// Rotates this unit vector towards the unit vector `target`, by no more
+    /// than `max_angle`. Returns `target` once it is within reach.
+    pub fn rotate_towards(self, target: Vector3<S>, max_angle: Rad<S>) -> Vector3<S> {
+        rotate_towards_unit(self, target, max_angle, || self.any_orthogonal())
+    }
+
+    /// Returns the signed angle from this vector to `other`, in the range
+    /// `[-π, π]`. The angle is positive when the rotation from this vector to
+    /// `other` is counterclockwise about `axis`, looking down the axis towards
+    /// the origin.
+    pub fn signed_angle(self, other: Vector3<S>, axis: Vector3<S>) -> Rad<S> {
+        let angle = self.angle(other);
+        if self.cross(other).dot(axis) < S::zero() {
+            -angle
+        } else {
+            angle
+        }
+    }
+}
+
 impl<S: BaseNum> Vector4<S> {
     /// A unit vector in the `x` direction.
     #[inline]
@@ -516,6 +795,54 @@ impl<S: BaseNum> Vector4<S> {
     impl_swizzle_functions!(Vector1, Vector2, Vector3, Vector4, S, xyzw);
 }
 
+/// Spherical linear interpolation between two unit vectors, rotating
+/// towards the vector returned by `perpendicular` when they are opposite.
+fn slerp_unit<V: InnerSpace, F>(a: V, b: V, amount: V::Scalar, perpendicular: F) -> V
+where
+    V::Scalar: BaseFloat,
+    F: FnOnce() -> V,
+{
+    let dot = a.dot(b);
+    let dot_threshold = cast(0.9995f64).unwrap();
+
+    // if the vectors are close together use `nlerp`
+    if dot > dot_threshold {
+        return a.lerp(b, amount).normalize();
+    }
+
+    // Rotate in the plane of both vectors, using the part of `b` that is
+    // perpendicular to `a`.
+    let theta = Rad::acos(dot.max(-V::Scalar::one()));
+    let rejected = b - a * dot;
+    let direction = if rejected.magnitude2() > V::Scalar::epsilon() {
+        rejected.normalize()
+    } else {
+        perpendicular()
+    };
+    let (sin, cos) = Rad::sin_cos(theta * amount);
+    a * cos + direction * sin
+}
+
+/// Rotates a unit vector towards another by at most `max_angle`.
+fn rotate_towards_unit<V: InnerSpace, F>(
+    a: V,
+    b: V,
+    max_angle: Rad<V::Scalar>,
+    perpendicular: F,
+) -> V
+where
+    V::Scalar: BaseFloat,
+    F: FnOnce() -> V,
+{
+    let dot = a.dot(b);
+    let theta = Rad::acos(dot.max(-V::Scalar::one()).min(V::Scalar::one()));
+    if theta <= max_angle {
+        b
+    } else {
+        slerp_unit(a, b, max_angle / theta, perpendicular)
+    }
+}
+
 /// Dot product of two vectors.
 #[inline]
 pub fn dot<V: InnerSpace>(a: V, b: V) -> V::Scalar
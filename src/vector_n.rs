@@ -0,0 +1,396 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::{Float, NumCast};
+use std::array;
+use std::fmt;
+use std::iter;
+use std::ops::*;
+
+use structure::*;
+
+use approx;
+use num::{BaseFloat, BaseNum};
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// An `N`-dimensional vector, backed by a fixed-size array.
+///
+/// This is the const-generic counterpart of `Vector1` to `Vector4`, for code
+/// that needs to be generic over the dimension. `VectorN<S, 3>` has the same
+/// layout as `Vector3<S>`, and converting between the two is free.
+///
+/// `N` must be at least one, and constructing a `VectorN<S, 0>` fails to
+/// compile:
+///
+/// ```compile_fail
+/// use cgmath::VectorN;
+///
+/// let v = VectorN::<f32, 0>::new([]);
+/// ```
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+pub struct VectorN<S, const N: usize> {
+    /// The components of the vector.
+    pub data: [S; N],
+}
+
+impl<S, const N: usize> VectorN<S, N> {
+    /// Evaluating this fails for `N = 0`, which rejects empty vectors when
+    /// they are constructed or reduced, rather than panicking in `sum`.
+    const NONEMPTY: () = assert!(N > 0, "a VectorN needs at least one component");
+
+    /// Construct a new vector from an array of components.
+    #[inline]
+    pub const fn new(data: [S; N]) -> VectorN<S, N> {
+        let () = Self::NONEMPTY;
+        VectorN { data }
+    }
+
+    /// Construct a new vector, calling `f` with the index of each component.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> VectorN<S, N>
+        where F: FnMut(usize) -> S
+    {
+        let () = Self::NONEMPTY;
+        VectorN { data: array::from_fn(f) }
+    }
+
+    /// Perform the given operation on each field in the vector, returning a new vector
+    /// constructed from the operations.
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> VectorN<U, N>
+        where F: FnMut(S) -> U
+    {
+        VectorN { data: self.data.map(f) }
+    }
+}
+
+impl<S: Copy, const N: usize> VectorN<S, N> {
+    /// Construct a new vector where each component is the result of
+    /// applying the given operation to each pair of components of the
+    /// given vectors.
+    #[inline]
+    pub fn zip<S2: Copy, S3, F>(self, v2: VectorN<S2, N>, mut f: F) -> VectorN<S3, N>
+        where F: FnMut(S, S2) -> S3
+    {
+        VectorN::from_fn(|i| f(self.data[i], v2.data[i]))
+    }
+}
+
+impl<S: NumCast + Copy, const N: usize> VectorN<S, N> {
+    /// Component-wise casting to another type.
+    #[inline]
+    pub fn cast<T: NumCast + Copy + Default>(&self) -> Option<VectorN<T, N>> {
+        let mut data = [T::default(); N];
+        for (to, &from) in data.iter_mut().zip(self.data.iter()) {
+            *to = match NumCast::from(from) {
+                Some(value) => value,
+                None => return None,
+            };
+        }
+        Some(VectorN { data })
+    }
+}
+
+impl<S: BaseNum, const N: usize> VectorN<S, N> {
+    /// A unit vector along the `i`th axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `N`.
+    #[inline]
+    pub fn unit(i: usize) -> VectorN<S, N> {
+        assert!(i < N, "axis index out of bounds");
+        VectorN::from_fn(|j| if i == j { S::one() } else { S::zero() })
+    }
+}
+
+impl<S: BaseNum, const N: usize> MetricSpace for VectorN<S, N> {
+    type Metric = S;
+
+    #[inline]
+    fn distance2(self, other: Self) -> S {
+        (other - self).magnitude2()
+    }
+}
+
+impl<S: Copy, const N: usize> Array for VectorN<S, N> {
+    type Element = S;
+
+    #[inline]
+    fn len() -> usize {
+        N
+    }
+
+    #[inline]
+    fn from_value(scalar: S) -> VectorN<S, N> {
+        let () = Self::NONEMPTY;
+        VectorN { data: [scalar; N] }
+    }
+
+    #[inline]
+    fn sum(self) -> S where S: Add<Output = S> {
+        let () = Self::NONEMPTY;
+        self.data[1..].iter().fold(self.data[0], |acc, &x| acc + x)
+    }
+
+    #[inline]
+    fn product(self) -> S where S: Mul<Output = S> {
+        let () = Self::NONEMPTY;
+        self.data[1..].iter().fold(self.data[0], |acc, &x| acc * x)
+    }
+
+    fn is_finite(&self) -> bool where S: Float {
+        self.data.iter().all(|x| x.is_finite())
+    }
+}
+
+impl<S: BaseNum, const N: usize> Zero for VectorN<S, N> {
+    #[inline]
+    fn zero() -> VectorN<S, N> {
+        VectorN::from_value(S::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == VectorN::zero()
+    }
+}
+
+impl<S: BaseNum, const N: usize> iter::Sum<VectorN<S, N>> for VectorN<S, N> {
+    #[inline]
+    fn sum<I: Iterator<Item=VectorN<S, N>>>(iter: I) -> VectorN<S, N> {
+        iter.fold(VectorN::zero(), Add::add)
+    }
+}
+
+impl<'a, S: 'a + BaseNum, const N: usize> iter::Sum<&'a VectorN<S, N>> for VectorN<S, N> {
+    #[inline]
+    fn sum<I: Iterator<Item=&'a VectorN<S, N>>>(iter: I) -> VectorN<S, N> {
+        iter.fold(VectorN::zero(), Add::add)
+    }
+}
+
+impl<S: BaseNum, const N: usize> VectorSpace for VectorN<S, N> {
+    type Scalar = S;
+}
+
+impl<S: BaseNum, const N: usize> InnerSpace for VectorN<S, N> {
+    #[inline]
+    fn dot(self, other: VectorN<S, N>) -> S {
+        self.mul_element_wise(other).sum()
+    }
+}
+
+impl<S: Neg<Output = S> + Copy, const N: usize> Neg for VectorN<S, N> {
+    type Output = VectorN<S, N>;
+
+    default_fn!(#[inline] neg(self) -> VectorN<S, N> { self.map(|x| -x) } );
+}
+
+impl<S: BaseFloat, const N: usize> approx::AbsDiffEq for VectorN<S, N> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        self.data.iter().zip(other.data.iter())
+            .all(|(a, b)| S::abs_diff_eq(a, b, epsilon))
+    }
+}
+
+impl<S: BaseFloat, const N: usize> approx::RelativeEq for VectorN<S, N> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        self.data.iter().zip(other.data.iter())
+            .all(|(a, b)| S::relative_eq(a, b, epsilon, max_relative))
+    }
+}
+
+impl<S: BaseFloat, const N: usize> approx::UlpsEq for VectorN<S, N> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        self.data.iter().zip(other.data.iter())
+            .all(|(a, b)| S::ulps_eq(a, b, epsilon, max_ulps))
+    }
+}
+
+impl_operator!(<S: BaseNum, const N: usize> Add<VectorN<S, N> > for VectorN<S, N> {
+    fn add(lhs, rhs) -> VectorN<S, N> { VectorN::from_fn(|i| lhs.data[i] + rhs.data[i]) }
+});
+impl_assignment_operator!(<S: BaseNum, const N: usize> AddAssign<VectorN<S, N> > for VectorN<S, N> {
+    fn add_assign(&mut self, other) { for i in 0..N { self.data[i] += other.data[i] } }
+});
+
+impl_operator!(<S: BaseNum, const N: usize> Sub<VectorN<S, N> > for VectorN<S, N> {
+    fn sub(lhs, rhs) -> VectorN<S, N> { VectorN::from_fn(|i| lhs.data[i] - rhs.data[i]) }
+});
+impl_assignment_operator!(<S: BaseNum, const N: usize> SubAssign<VectorN<S, N> > for VectorN<S, N> {
+    fn sub_assign(&mut self, other) { for i in 0..N { self.data[i] -= other.data[i] } }
+});
+
+impl_operator!(<S: BaseNum, const N: usize> Mul<S> for VectorN<S, N> {
+    fn mul(vector, scalar) -> VectorN<S, N> { vector.map(|x| x * scalar) }
+});
+impl_assignment_operator!(<S: BaseNum, const N: usize> MulAssign<S> for VectorN<S, N> {
+    fn mul_assign(&mut self, scalar) { for x in self.data.iter_mut() { *x *= scalar } }
+});
+
+impl_operator!(<S: BaseNum, const N: usize> Div<S> for VectorN<S, N> {
+    fn div(vector, scalar) -> VectorN<S, N> { vector.map(|x| x / scalar) }
+});
+impl_assignment_operator!(<S: BaseNum, const N: usize> DivAssign<S> for VectorN<S, N> {
+    fn div_assign(&mut self, scalar) { for x in self.data.iter_mut() { *x /= scalar } }
+});
+
+impl_operator!(<S: BaseNum, const N: usize> Rem<S> for VectorN<S, N> {
+    fn rem(vector, scalar) -> VectorN<S, N> { vector.map(|x| x % scalar) }
+});
+impl_assignment_operator!(<S: BaseNum, const N: usize> RemAssign<S> for VectorN<S, N> {
+    fn rem_assign(&mut self, scalar) { for x in self.data.iter_mut() { *x %= scalar } }
+});
+
+impl<S: BaseNum, const N: usize> ElementWise for VectorN<S, N> {
+    default_fn!(#[inline] add_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, |a, b| a + b) } );
+    default_fn!(#[inline] sub_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, |a, b| a - b) } );
+    default_fn!(#[inline] mul_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, |a, b| a * b) } );
+    default_fn!(#[inline] div_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, |a, b| a / b) } );
+    #[inline] fn rem_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, |a, b| a % b) }
+
+    default_fn!(#[inline] add_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self.data[i] += rhs.data[i] } } );
+    default_fn!(#[inline] sub_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self.data[i] -= rhs.data[i] } } );
+    default_fn!(#[inline] mul_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self.data[i] *= rhs.data[i] } } );
+    default_fn!(#[inline] div_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self.data[i] /= rhs.data[i] } } );
+    #[inline] fn rem_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self.data[i] %= rhs.data[i] } }
+}
+
+impl<S: BaseNum, const N: usize> ElementWise<S> for VectorN<S, N> {
+    default_fn!(#[inline] add_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x + rhs) } );
+    default_fn!(#[inline] sub_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x - rhs) } );
+    default_fn!(#[inline] mul_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x * rhs) } );
+    default_fn!(#[inline] div_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x / rhs) } );
+    #[inline] fn rem_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x % rhs) }
+
+    default_fn!(#[inline] add_assign_element_wise(&mut self, rhs: S) { for x in self.data.iter_mut() { *x += rhs } } );
+    default_fn!(#[inline] sub_assign_element_wise(&mut self, rhs: S) { for x in self.data.iter_mut() { *x -= rhs } } );
+    default_fn!(#[inline] mul_assign_element_wise(&mut self, rhs: S) { for x in self.data.iter_mut() { *x *= rhs } } );
+    default_fn!(#[inline] div_assign_element_wise(&mut self, rhs: S) { for x in self.data.iter_mut() { *x /= rhs } } );
+    #[inline] fn rem_assign_element_wise(&mut self, rhs: S) { for x in self.data.iter_mut() { *x %= rhs } }
+}
+
+impl<S, const N: usize> Index<usize> for VectorN<S, N> {
+    type Output = S;
+
+    #[inline]
+    fn index(&self, i: usize) -> &S {
+        &self.data[i]
+    }
+}
+
+impl<S, const N: usize> IndexMut<usize> for VectorN<S, N> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut S {
+        &mut self.data[i]
+    }
+}
+
+impl<S, const N: usize> From<[S; N]> for VectorN<S, N> {
+    #[inline]
+    fn from(data: [S; N]) -> VectorN<S, N> {
+        VectorN { data }
+    }
+}
+
+impl<S, const N: usize> Into<[S; N]> for VectorN<S, N> {
+    #[inline]
+    fn into(self) -> [S; N] {
+        self.data
+    }
+}
+
+impl<S, const N: usize> AsRef<[S; N]> for VectorN<S, N> {
+    #[inline]
+    fn as_ref(&self) -> &[S; N] {
+        &self.data
+    }
+}
+
+impl<S, const N: usize> AsMut<[S; N]> for VectorN<S, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; N] {
+        &mut self.data
+    }
+}
+
+impl<S: fmt::Debug, const N: usize> fmt::Debug for VectorN<S, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VectorN ")?;
+        <[S; N] as fmt::Debug>::fmt(&self.data, f)
+    }
+}
+
+macro_rules! impl_fixed_vector_conversions {
+    ($VectorN:ident, $n:expr) => {
+        impl<S: Copy> From<$VectorN<S>> for VectorN<S, $n> {
+            #[inline]
+            fn from(v: $VectorN<S>) -> VectorN<S, $n> {
+                VectorN { data: v.into() }
+            }
+        }
+
+        impl<S: Copy> From<VectorN<S, $n>> for $VectorN<S> {
+            #[inline]
+            fn from(v: VectorN<S, $n>) -> $VectorN<S> {
+                $VectorN::from(v.data)
+            }
+        }
+
+        impl<S> AsRef<$VectorN<S>> for VectorN<S, $n> {
+            #[inline]
+            fn as_ref(&self) -> &$VectorN<S> {
+                (&self.data).into()
+            }
+        }
+
+        impl<S> AsMut<$VectorN<S>> for VectorN<S, $n> {
+            #[inline]
+            fn as_mut(&mut self) -> &mut $VectorN<S> {
+                (&mut self.data).into()
+            }
+        }
+    }
+}
+
+impl_fixed_vector_conversions!(Vector1, 1);
+impl_fixed_vector_conversions!(Vector2, 2);
+impl_fixed_vector_conversions!(Vector3, 3);
+impl_fixed_vector_conversions!(Vector4, 4);
+
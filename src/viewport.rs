@@ -0,0 +1,200 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::{cast, Float};
+
+use structure::*;
+
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::{Point2, Point3};
+use projection::{ClipSpace, DepthRange, YAxis};
+use vector::Vector3;
+
+/// A viewport, which maps normalized device coordinates to window
+/// coordinates.
+///
+/// The y axis of the window coordinates is given by `y_axis`. With
+/// `YAxis::Up` the origin is at the bottom left of the window, as in OpenGL.
+/// With `YAxis::Down` the origin is at the top left of the window, as in
+/// Direct3D, Vulkan, Metal, WebGPU and most windowing systems.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Viewport<S> {
+    pub x: S,
+    pub y: S,
+    pub width: S,
+    pub height: S,
+    pub min_depth: S,
+    pub max_depth: S,
+    pub y_axis: YAxis,
+}
+
+impl<S: BaseFloat> Viewport<S> {
+    /// Map a point in normalized device coordinates to window coordinates.
+    pub fn ndc_to_window(&self, ndc: Point3<S>, clip_space: ClipSpace) -> Point3<S> {
+        let half: S = cast(0.5f64).unwrap();
+        let up = if clip_space.y_axis == YAxis::Up {
+            ndc.y
+        } else {
+            -ndc.y
+        };
+        let up = (up + S::one()) * half;
+        let depth = match clip_space.depth_range {
+            DepthRange::NegativeOneToOne => (ndc.z + S::one()) * half,
+            DepthRange::ZeroToOne => ndc.z,
+        };
+
+        Point3::new(
+            self.x + (ndc.x + S::one()) * half * self.width,
+            self.y
+                + self.height
+                    * if self.y_axis == YAxis::Up {
+                        up
+                    } else {
+                        S::one() - up
+                    },
+            self.min_depth + depth * (self.max_depth - self.min_depth),
+        )
+    }
+
+    /// Map a point in window coordinates to normalized device coordinates.
+    pub fn window_to_ndc(&self, window: Point3<S>, clip_space: ClipSpace) -> Point3<S> {
+        let two: S = cast(2).unwrap();
+        let up = (window.y - self.y) / self.height;
+        let up = if self.y_axis == YAxis::Up {
+            up
+        } else {
+            S::one() - up
+        };
+        let up = up * two - S::one();
+        let depth = (window.z - self.min_depth) / (self.max_depth - self.min_depth);
+
+        Point3::new(
+            (window.x - self.x) / self.width * two - S::one(),
+            if clip_space.y_axis == YAxis::Up {
+                up
+            } else {
+                -up
+            },
+            match clip_space.depth_range {
+                DepthRange::NegativeOneToOne => depth * two - S::one(),
+                DepthRange::ZeroToOne => depth,
+            },
+        )
+    }
+
+    /// Map a point in world space to window coordinates, like [`gluProject`].
+    ///
+    /// Returns `None` if the point projects to infinity, which happens when it
+    /// lies on the plane of the eye for a perspective projection.
+    ///
+    /// [`gluProject`]: https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluProject.xml
+    pub fn project(
+        &self,
+        point: Point3<S>,
+        view: Matrix4<S>,
+        projection: Matrix4<S>,
+        clip_space: ClipSpace,
+    ) -> Option<Point3<S>> {
+        let clip = projection * (view * point.to_homogeneous());
+        Some(self.ndc_to_window(Point3::from_homogeneous(clip)?, clip_space))
+    }
+
+    /// Map a point in window coordinates back to world space, like
+    /// [`gluUnProject`].
+    ///
+    /// Returns `None` if the combined view and projection matrix is not
+    /// invertible, or if the point unprojects to infinity.
+    ///
+    /// [`gluUnProject`]: https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluUnProject.xml
+    pub fn unproject(
+        &self,
+        window: Point3<S>,
+        view: Matrix4<S>,
+        projection: Matrix4<S>,
+        clip_space: ClipSpace,
+    ) -> Option<Point3<S>> {
+        let inverse = (projection * view).invert()?;
+        let ndc = self.window_to_ndc(window, clip_space);
+        Point3::from_homogeneous(inverse * ndc.to_homogeneous())
+    }
+
+    /// Build a world space ray through the given window position, such as
+    /// the position of the mouse, for picking objects in the scene.
+    ///
+    /// The ray is returned as its origin on the near plane and its normalized
+    /// direction. Returns `None` if the combined view and projection matrix is
+    /// not invertible.
+    pub fn pick_ray(
+        &self,
+        window: Point2<S>,
+        view: Matrix4<S>,
+        projection: Matrix4<S>,
+        clip_space: ClipSpace,
+    ) -> Option<(Point3<S>, Vector3<S>)> {
+        let inverse = (projection * view).invert()?;
+        let ndc = self.window_to_ndc(Point3::new(window.x, window.y, S::zero()), clip_space);
+
+        // Unproject a point on the near plane, and one halfway to the far
+        // plane so that this also works for an infinite far plane.
+        let (near, middle) = match (clip_space.depth_range, clip_space.reverse_z) {
+            (DepthRange::NegativeOneToOne, false) => (-S::one(), S::zero()),
+            (DepthRange::NegativeOneToOne, true) => (S::one(), S::zero()),
+            (DepthRange::ZeroToOne, false) => (S::zero(), cast(0.5f64).unwrap()),
+            (DepthRange::ZeroToOne, true) => (S::one(), cast(0.5f64).unwrap()),
+        };
+        let origin =
+            Point3::from_homogeneous(inverse * Point3::new(ndc.x, ndc.y, near).to_homogeneous())?;
+        let middle =
+            Point3::from_homogeneous(inverse * Point3::new(ndc.x, ndc.y, middle).to_homogeneous())?;
+        let direction = (middle - origin).normalize();
+        Some((origin, direction))
+    }
+}
+
+/// Convert a position in logical pixels to physical pixels, using the scale
+/// factor of the display.
+#[inline]
+pub fn logical_to_physical<S: BaseFloat>(point: Point2<S>, scale_factor: S) -> Point2<S> {
+    point * scale_factor
+}
+
+/// Convert a position in physical pixels to logical pixels, using the scale
+/// factor of the display.
+#[inline]
+pub fn physical_to_logical<S: BaseFloat>(point: Point2<S>, scale_factor: S) -> Point2<S> {
+    point / scale_factor
+}
+
+/// Snap a position in logical pixels to the nearest corner of a physical
+/// pixel, so that edges of filled shapes stay sharp.
+pub fn snap_to_pixel<S: BaseFloat>(point: Point2<S>, scale_factor: S) -> Point2<S> {
+    let physical = logical_to_physical(point, scale_factor);
+    let snapped = Point2::new(Float::round(physical.x), Float::round(physical.y));
+    physical_to_logical(snapped, scale_factor)
+}
+
+/// Snap a position in logical pixels to the center of the physical pixel
+/// containing it, so that lines one physical pixel wide stay sharp.
+pub fn snap_to_pixel_center<S: BaseFloat>(point: Point2<S>, scale_factor: S) -> Point2<S> {
+    let half: S = cast(0.5f64).unwrap();
+    let physical = logical_to_physical(point, scale_factor);
+    let snapped = Point2::new(
+        Float::floor(physical.x) + half,
+        Float::floor(physical.y) + half,
+    );
+    physical_to_logical(snapped, scale_factor)
+}
@@ -0,0 +1,146 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::camera::*;
+use cgmath::*;
+
+#[test]
+fn test_cube_face_views() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let proj = Matrix4::from(cube_face_projection(0.1, 10.0));
+    let views = cube_face_views(eye);
+
+    for (&face, &view) in CubeFace::ALL.iter().zip(views.iter()) {
+        assert_eq!(view, face.view(eye));
+
+        // The center of the face projects to the center of the viewport.
+        let p = view.transform_point(eye + face.direction() * 5.0);
+        let clip = proj * p.to_homogeneous();
+        assert_relative_eq!(clip.x / clip.w, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(clip.y / clip.w, 0.0, epsilon = 1e-12);
+
+        // The edges of the face are at the edges of the viewport.
+        let edge = face.direction::<f64>() + face.up();
+        let p = view.transform_point(eye + edge);
+        let clip = proj * p.to_homogeneous();
+        assert_relative_eq!(clip.y / clip.w, 1.0, epsilon = 1e-12);
+    }
+
+    // The side faces have y pointing down.
+    let p = views[0].transform_vector(Vector3::unit_y());
+    assert_relative_eq!(p, -Vector3::unit_y());
+}
+
+#[test]
+fn test_cascade_splits() {
+    let uniform = cascade_splits(1.0f64, 100.0, 4, 0.0);
+    assert_relative_eq!(uniform[..], [1.0, 25.75, 50.5, 75.25, 100.0][..]);
+
+    let log = cascade_splits(1.0f64, 1000.0, 3, 1.0);
+    assert_relative_eq!(log[..], [1.0, 10.0, 100.0, 1000.0][..], epsilon = 1e-9);
+
+    let practical = cascade_splits(1.0f64, 100.0, 4, 0.5);
+    assert_eq!(practical.len(), 5);
+    assert_eq!(practical[0], 1.0);
+    assert_eq!(practical[4], 100.0);
+    for i in 1..4 {
+        assert_relative_eq!(
+            practical[i],
+            (uniform[i] + 100.0f64.powf(i as f64 / 4.0)) / 2.0
+        );
+    }
+}
+
+#[test]
+fn test_fit_cascade() {
+    let camera = PerspectiveFov {
+        fovy: Deg(60.0f64).into(),
+        aspect: 16.0 / 9.0,
+        near: 0.1,
+        far: 100.0,
+    };
+    let view = Matrix4::look_at(
+        Point3::new(3.0, 2.0, 1.0),
+        Point3::new(0.0, 0.0, -5.0),
+        Vector3::unit_y(),
+    );
+    let light_view = Matrix4::look_at_dir(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(-1.0, -2.0, -0.5),
+        Vector3::unit_y(),
+    );
+    let ortho = fit_cascade(&camera, view, 5.0, 20.0, light_view, 1024).unwrap();
+    let light_proj = Matrix4::from(ortho);
+
+    // The texel size is a whole fraction of the extents.
+    let texel = (ortho.right - ortho.left) / 1024.0;
+    assert!(((ortho.left / texel).round() - ortho.left / texel).abs() < 1e-6);
+
+    // Every point in the slice is inside the shadow map.
+    let to_light = light_proj * light_view * view.invert().unwrap();
+    let tan_y = (camera.fovy / 2.0).tan();
+    for &distance in [5.0, 12.0, 20.0].iter() {
+        for &(sx, sy) in [(-1.0, -1.0), (1.0, 0.5), (0.0, 1.0), (1.0, 1.0)].iter() {
+            let p = Point3::new(
+                sx * tan_y * camera.aspect * distance,
+                sy * tan_y * distance,
+                -distance,
+            );
+            let p = to_light.transform_point(p);
+            assert!(p.x.abs() <= 1.0 + 1e-9 && p.y.abs() <= 1.0 + 1e-9);
+            assert!(p.z.abs() <= 1.0 + 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_fit_cascade_rotation() {
+    let camera = PerspectiveFov {
+        fovy: Deg(60.0f64).into(),
+        aspect: 16.0 / 9.0,
+        near: 0.1,
+        far: 100.0,
+    };
+    let light_view = Matrix4::look_at_dir(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(-1.0, -2.0, -0.5),
+        Vector3::unit_y(),
+    );
+    let eye = Point3::new(3.0, 2.0, 1.0);
+    let fit = |yaw: f64| {
+        let dir = Vector3::new(yaw.sin(), -0.2, -yaw.cos());
+        let view = Matrix4::look_at_dir(eye, dir, Vector3::unit_y());
+        fit_cascade(&camera, view, 5.0, 20.0, light_view, 1024).unwrap()
+    };
+
+    let first = fit(0.0);
+    let texel = (first.right - first.left) / 1024.0;
+    for i in 1..16 {
+        let ortho = fit(i as f64 * 0.1);
+
+        // Rotating the camera does not change the texel size.
+        assert!(((ortho.right - ortho.left) / 1024.0 - texel).abs() < 1e-12);
+        assert!(((ortho.top - ortho.bottom) / 1024.0 - texel).abs() < 1e-12);
+
+        // The bounds only move by whole texels.
+        let dx = (ortho.left - first.left) / texel;
+        let dy = (ortho.bottom - first.bottom) / texel;
+        assert!((dx.round() - dx).abs() < 1e-6);
+        assert!((dy.round() - dy).abs() < 1e-6);
+    }
+}
@@ -204,6 +204,22 @@ pub mod matrix2 {
         let rot2 = Matrix2::look_at(-V, Vector2::unit_y());
         assert_eq!(rot2 * Vector2::unit_x(), (-V).normalize());
     }
+
+    #[test]
+    fn test_exp_log() {
+        // The generator of a rotation
+        let generator = Matrix2::new(0.0f64, 1.0, -1.0, 0.0) * 0.5;
+        let rotation = Matrix2::from_angle(Rad(0.5f64));
+        assert_relative_eq!(generator.exp(), rotation, epsilon = 1e-12);
+        assert_relative_eq!(rotation.log().unwrap(), generator, epsilon = 1e-12);
+        assert_relative_eq!(
+            rotation.powf(3.0).unwrap(),
+            Matrix2::from_angle(Rad(1.5f64)),
+            epsilon = 1e-12
+        );
+
+        assert!(Matrix2::new(-1.0f64, 0.0, 0.0, 2.0).log().is_none());
+    }
 }
 
 pub mod matrix3 {
@@ -446,6 +462,117 @@ pub mod matrix3 {
         assert_eq!(res, Vector3::new(1., 2., 1.));
     }
 
+    #[test]
+    fn test_adjugate() {
+        let m = Matrix3::new(2.0f64, 0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 1.0, 4.0);
+        assert_ulps_eq!(m.adjugate(), m.invert().unwrap() * m.determinant());
+        assert_ulps_eq!(m.cofactor(), m.adjugate().transpose());
+
+        // The adjugate of a singular matrix is still defined
+        assert_eq!(A.determinant(), 0.0);
+        assert_eq!(A * A.adjugate(), Matrix3::zero());
+    }
+
+    #[test]
+    fn test_normal_matrix() {
+        let m = Matrix3::from_angle_z(Deg(30.0f64)) * Matrix3::from_nonuniform_scale(2.0, 0.5);
+        let expected = m.invert().unwrap().transpose();
+        let n = Vector3::new(1.0f64, 1.0, 0.0).normalize();
+        assert_ulps_eq!((m.normal_matrix() * n).normalize(), (expected * n).normalize());
+
+        // Mirroring keeps the normals pointing the same way
+        let mirror = Matrix3::from_nonuniform_scale(-1.0f64, 1.0);
+        assert_eq!(mirror.normal_matrix() * Vector3::unit_x(), -Vector3::unit_x());
+
+        // Flattening onto the xy plane keeps the z normal
+        let flatten = Matrix3::from_diagonal(Vector3::new(1.0f64, 1.0, 0.0));
+        assert_eq!(flatten.normal_matrix() * Vector3::unit_z(), Vector3::unit_z());
+    }
+
+    #[test]
+    fn test_project_point() {
+        // A homography with w = x + 1
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let m = Matrix3::new(
+            1.0f64, 0.0, 1.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0,
+        );
+        assert_eq!(m.project_point(Point2::new(1.0, 2.0)), Some(Point2::new(0.5, 1.0)));
+        assert_eq!(m.project_point(Point2::new(-1.0, 3.0)), None);
+
+        // Affine transforms agree with `transform_point`
+        let affine =
+            Matrix3::from_translation(Vector2::new(1.0f64, 2.0)) * Matrix3::from_angle_z(Deg(30.0));
+        let p = Point2::new(3.0, -4.0);
+        assert_ulps_eq!(affine.project_point(p).unwrap(), affine.transform_point(p));
+    }
+
+    #[test]
+    fn test_hat_vee() {
+        let v = Vector3::new(1.0f64, -2.0, 3.0);
+        let u = Vector3::new(4.0f64, 5.0, -6.0);
+        assert_eq!(Matrix3::hat(v) * u, v.cross(u));
+        assert_eq!(Matrix3::hat(v).vee(), v);
+        assert_eq!(Matrix3::hat(v).transpose(), -Matrix3::hat(v));
+    }
+
+    #[test]
+    fn test_exp_log() {
+        let axis = Vector3::new(1.0f64, 2.0, 3.0).normalize();
+        let rotation = Matrix3::from_axis_angle(Unit::new(axis).unwrap(), Rad(1.2));
+        assert_ulps_eq!(Matrix3::hat(axis * 1.2).exp(), rotation);
+        assert_ulps_eq!(rotation.log().unwrap(), Matrix3::hat(axis * 1.2));
+        assert_eq!(Matrix3::<f64>::zero().exp(), Matrix3::identity());
+        assert_eq!(Matrix3::<f64>::identity().log(), Some(Matrix3::zero()));
+
+        let small = Matrix3::hat(axis * 1.0e-9);
+        assert_ulps_eq!(small.exp().log().unwrap(), small);
+    }
+
+    #[test]
+    fn test_exp_log_general() {
+        assert_relative_eq!(
+            Matrix3::from_value(1.0f64).exp(),
+            Matrix3::from_value(1.0f64.exp()),
+            epsilon = 1e-12
+        );
+
+        let m = Matrix3::new(1.5f64, 0.25, 0.0, -0.5, 2.0, 0.1, 0.3, 0.0, 0.75);
+        let log = m.log().expect("Expected a real logarithm");
+        assert_relative_eq!(log.exp(), m, epsilon = 1e-12);
+        let half = m.powf(0.5).unwrap();
+        assert_relative_eq!(half * half, m, epsilon = 1e-12);
+
+        // A reflection has a negative eigenvalue
+        let mirror = Matrix3::from_nonuniform_scale(-1.0f64, 1.0);
+        assert!(mirror.log().is_none());
+        assert!(mirror.powf(0.5).is_none());
+    }
+
+    #[test]
+    fn test_log_half_turn() {
+        let axis = Vector3::new(2.0f64, -1.0, 2.0).normalize();
+        for &angle in [3.0f64, 3.14, std::f64::consts::PI].iter() {
+            let rotation = Matrix3::from_axis_angle(Unit::new(axis).unwrap(), Rad(angle));
+            let log = rotation.log().unwrap();
+            assert_relative_eq!(log.exp(), rotation, epsilon = 1e-12);
+            assert_relative_eq!(log.vee().magnitude(), angle, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_powf() {
+        let axis = Unit::new(Vector3::new(0.0f64, 1.0, 0.0)).unwrap();
+        let rotation = Matrix3::from_axis_angle(axis, Deg(90.0));
+        assert_ulps_eq!(
+            rotation.powf(0.5).unwrap(),
+            Matrix3::from_axis_angle(axis, Deg(45.0))
+        );
+        assert_ulps_eq!(rotation.powf(0.0).unwrap(), Matrix3::identity());
+        assert_ulps_eq!(rotation.powf(1.0).unwrap(), rotation);
+    }
+
     mod from_axis_x {
         use cgmath::*;
 
@@ -532,7 +659,7 @@ pub mod matrix3 {
             use cgmath::*;
 
             fn check_from_axis_angle_x(pitch: Rad<f32>) {
-                let found = Matrix3::from_axis_angle(Vector3::unit_x(), pitch);
+                let found = Matrix3::from_axis_angle(Unit::unit_x(), pitch);
                 let expected = Matrix3::from(Euler {
                     x: pitch,
                     y: Rad(0.0),
@@ -559,7 +686,7 @@ pub mod matrix3 {
             use cgmath::*;
 
             fn check_from_axis_angle_y(yaw: Rad<f32>) {
-                let found = Matrix3::from_axis_angle(Vector3::unit_y(), yaw);
+                let found = Matrix3::from_axis_angle(Unit::unit_y(), yaw);
                 let expected = Matrix3::from(Euler {
                     x: Rad(0.0),
                     y: yaw,
@@ -586,7 +713,7 @@ pub mod matrix3 {
             use cgmath::*;
 
             fn check_from_axis_angle_z(roll: Rad<f32>) {
-                let found = Matrix3::from_axis_angle(Vector3::unit_z(), roll);
+                let found = Matrix3::from_axis_angle(Unit::unit_z(), roll);
                 let expected = Matrix3::from(Euler {
                     x: Rad(0.0),
                     y: Rad(0.0),
@@ -697,7 +824,8 @@ pub mod matrix3 {
         fn test_xy() {
             let vec = vec3(0.0, 0.0, 1.0);
 
-            let rot = Matrix3::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), Deg(90.0));
+            let axis = Unit::try_normalize(vec3(1.0, 1.0, 0.0)).unwrap();
+            let rot = Matrix3::from_axis_angle(axis, Deg(90.0));
             assert_ulps_eq!(
                 vec3(2.0f32.sqrt() / 2.0, -2.0f32.sqrt() / 2.0, 0.0),
                 rot * vec
@@ -708,7 +836,8 @@ pub mod matrix3 {
         fn test_yz() {
             let vec = vec3(1.0, 0.0, 0.0);
 
-            let rot = Matrix3::from_axis_angle(vec3(0.0, 1.0, 1.0).normalize(), Deg(-90.0));
+            let axis = Unit::try_normalize(vec3(0.0, 1.0, 1.0)).unwrap();
+            let rot = Matrix3::from_axis_angle(axis, Deg(-90.0));
             assert_ulps_eq!(
                 vec3(0.0, -2.0f32.sqrt() / 2.0, 2.0f32.sqrt() / 2.0),
                 rot * vec
@@ -719,7 +848,8 @@ pub mod matrix3 {
         fn test_xz() {
             let vec = vec3(0.0, 1.0, 0.0);
 
-            let rot = Matrix3::from_axis_angle(vec3(1.0, 0.0, 1.0).normalize(), Deg(90.0));
+            let axis = Unit::try_normalize(vec3(1.0, 0.0, 1.0)).unwrap();
+            let rot = Matrix3::from_axis_angle(axis, Deg(90.0));
             assert_ulps_eq!(
                 vec3(-2.0f32.sqrt() / 2.0, 0.0, 2.0f32.sqrt() / 2.0),
                 rot * vec
@@ -1102,6 +1232,104 @@ pub mod matrix4 {
         assert_eq!(res, Vector4::new(1., 2., 3., 1.));
     }
 
+    #[test]
+    fn test_adjugate() {
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0))
+            * Matrix4::from_angle_y(Deg(30.0))
+            * Matrix4::from_nonuniform_scale(2.0, 3.0, 4.0);
+        assert_ulps_eq!(m.adjugate(), m.invert().unwrap() * m.determinant());
+        assert_ulps_eq!(m.cofactor(), m.adjugate().transpose());
+        assert_eq!(A * A.adjugate(), Matrix4::zero());
+    }
+
+    #[test]
+    fn test_upper_left_3x3() {
+        assert_eq!(
+            A.upper_left_3x3(),
+            Matrix3::new(1.0, 5.0, 9.0, 2.0, 6.0, 10.0, 3.0, 7.0, 11.0)
+        );
+    }
+
+    #[test]
+    fn test_normal_matrix() {
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0))
+            * Matrix4::from_angle_x(Deg(60.0))
+            * Matrix4::from_nonuniform_scale(1.0, 3.0, 0.5);
+        let expected = m.upper_left_3x3().invert().unwrap().transpose();
+        let n = Vector3::new(0.0f64, 1.0, 1.0).normalize();
+        assert_ulps_eq!((m.normal_matrix() * n).normalize(), (expected * n).normalize());
+    }
+
+    #[test]
+    fn test_project_point() {
+        let m = perspective(Deg(90.0f64), 1.0, 1.0, 10.0);
+        assert_ulps_eq!(
+            m.project_point(Point3::new(1.0, -1.0, -1.0)).unwrap(),
+            Point3::new(1.0, -1.0, -1.0)
+        );
+        assert_ulps_eq!(
+            m.project_point(Point3::new(0.0, 5.0, -10.0)).unwrap(),
+            Point3::new(0.0, 0.5, 1.0)
+        );
+        // Points on the plane of the eye are mapped to infinity
+        assert_eq!(m.project_point(Point3::new(1.0, 2.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(Matrix4::<f64>::zero().exp(), Matrix4::identity());
+
+        // The generator of a translation
+        let mut generator = Matrix4::zero();
+        generator.w = Vector4::new(1.0f64, 2.0, 3.0, 0.0);
+        assert_ulps_eq!(
+            generator.exp(),
+            Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        );
+
+        let axis = Vector3::new(1.0f64, 2.0, 3.0).normalize();
+        let mut generator = Matrix4::from(Matrix3::hat(axis * 2.5));
+        generator.w.w = 0.0;
+        assert_ulps_eq!(
+            generator.exp(),
+            Matrix4::from_axis_angle(Unit::new(axis).unwrap(), Rad(2.5))
+        );
+        assert_ulps_eq!(
+            Matrix4::from_value(10.0f64).exp(),
+            Matrix4::from_value(10.0f64.exp()),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_log() {
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, -2.0, 3.0))
+            * Matrix4::from_axis_angle(
+                Unit::try_normalize(Vector3::new(1.0f64, 2.0, 3.0)).unwrap(),
+                Deg(70.0),
+            )
+            * Matrix4::from_nonuniform_scale(1.5, 2.0, 0.5);
+        let log = m.log().expect("Expected a real logarithm");
+        assert_relative_eq!(log.exp(), m, epsilon = 1e-12);
+
+        assert!(Matrix4::<f64>::zero().log().is_none());
+        assert!(Matrix4::from_nonuniform_scale(-1.0f64, 1.0, 1.0).log().is_none());
+    }
+
+    #[test]
+    fn test_powf() {
+        let m = Matrix4::from_translation(Vector3::new(4.0f64, 0.0, 0.0))
+            * Matrix4::from_angle_z(Deg(90.0));
+        let half = m.powf(0.5).unwrap();
+        assert_relative_eq!(half * half, m, epsilon = 1e-12);
+        assert_relative_eq!(
+            half,
+            Matrix4::from_translation(Vector3::new(2.0, 2.0 * (1.0 - 2.0f64.sqrt()), 0.0))
+                * Matrix4::from_angle_z(Deg(45.0)),
+            epsilon = 1e-12
+        );
+    }
+
     #[test]
     fn test_cast() {
         assert_ulps_eq!(
@@ -1143,3 +1371,360 @@ pub mod matrix4 {
         }
     }
 }
+
+pub mod matrix2x3 {
+    use cgmath::*;
+
+    const A: Matrix2x3<f64> = Matrix2x3 {
+        x: Vector3 {
+            x: 1.0f64,
+            y: 2.0f64,
+            z: 3.0f64,
+        },
+        y: Vector3 {
+            x: 4.0f64,
+            y: 5.0f64,
+            z: 6.0f64,
+        },
+    };
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Matrix2x3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0), A);
+        assert_eq!(
+            Matrix2x3::from_cols(Vector3::new(1.0f64, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)),
+            A
+        );
+    }
+
+    #[test]
+    fn test_row() {
+        assert_eq!(A.row(0), Vector2::new(1.0f64, 4.0));
+        assert_eq!(A.row(1), Vector2::new(2.0f64, 5.0));
+        assert_eq!(A.row(2), Vector2::new(3.0f64, 6.0));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t: Matrix3x2<f64> = A.transpose();
+        assert_eq!(t, Matrix3x2::new(1.0f64, 4.0, 2.0, 5.0, 3.0, 6.0));
+        assert_eq!(t.transpose(), A);
+    }
+
+    #[test]
+    fn test_add_sub_neg() {
+        assert_eq!(A + A, A * 2.0);
+        assert_eq!(A - A, Matrix2x3::zero());
+        assert_eq!(-A, Matrix2x3::new(-1.0f64, -2.0, -3.0, -4.0, -5.0, -6.0));
+        assert_eq!(2.0 * A, Matrix2x3::new(2.0f64, 4.0, 6.0, 8.0, 10.0, 12.0));
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(A * Vector2::new(1.0f64, 2.0), Vector3::new(9.0f64, 12.0, 15.0));
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        let ata: Matrix2<f64> = A.transpose() * A;
+        assert_eq!(ata, Matrix2::new(14.0f64, 32.0, 32.0, 77.0));
+
+        let aat: Matrix3<f64> = A * A.transpose();
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        assert_eq!(aat, Matrix3::new(
+            17.0f64, 22.0, 27.0,
+            22.0, 29.0, 36.0,
+            27.0, 36.0, 45.0,
+        ));
+    }
+
+    #[test]
+    fn test_mul_identity() {
+        assert_eq!(A * Matrix2::identity(), A);
+        assert_eq!(Matrix3::identity() * A, A);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let arr: [[f64; 3]; 2] = A.into();
+        assert_eq!(arr, [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(Matrix2x3::from(arr), A);
+
+        let flat: &[f64; 6] = A.as_ref();
+        assert_eq!(flat, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_ulps_eq!(
+            Matrix2x3::new(0.2f64, 1.5, 4.7, 2.3, 5.7, 2.1).cast().unwrap(),
+            Matrix2x3::new(0.2f32, 1.5, 4.7, 2.3, 5.7, 2.1)
+        );
+    }
+}
+
+pub mod matrix3x4 {
+    use cgmath::*;
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const A: Matrix3x4<f64> = Matrix3x4::new(
+        1.0f64, 2.0f64, 3.0f64, 4.0f64,
+        5.0f64, 6.0f64, 7.0f64, 8.0f64,
+        9.0f64, 10.0f64, 11.0f64, 12.0f64,
+    );
+
+    #[test]
+    fn test_transpose() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        assert_eq!(A.transpose(), Matrix4x3::new(
+            1.0f64, 5.0, 9.0,
+            2.0, 6.0, 10.0,
+            3.0, 7.0, 11.0,
+            4.0, 8.0, 12.0,
+        ));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut m = A;
+        m.swap_columns(0, 2);
+        assert_eq!(m.x, A.z);
+        assert_eq!(m.z, A.x);
+
+        let mut m = A;
+        m.swap_rows(0, 3);
+        assert_eq!(m.row(0), A.row(3));
+        assert_eq!(m.row(3), A.row(0));
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(
+            A * Vector3::new(1.0f64, 0.0, -1.0),
+            Vector4::new(-8.0f64, -8.0, -8.0, -8.0)
+        );
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0));
+        assert_eq!(m * A, Matrix3x4::from_cols(m * A.x, m * A.y, m * A.z));
+
+        let b: Matrix2x3<f64> = Matrix2x3::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(A * b, Matrix2x4::from_cols(A.x, A.z));
+
+        let c: Matrix4x3<f64> = A.transpose();
+        assert_eq!(c * A, Matrix3::from_cols(c * A.x, c * A.y, c * A.z));
+        assert_eq!(A * c, (c.transpose() * A.transpose()).transpose());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let arr: [[f64; 4]; 3] = A.into();
+        assert_eq!(arr[2], [9.0, 10.0, 11.0, 12.0]);
+        assert_eq!(conv::array3x4(A), arr);
+        assert_eq!(Matrix3x4::from(arr), A);
+    }
+}
+
+pub mod matrix3x2 {
+    use cgmath::*;
+
+    const A: Matrix3x2<f64> = Matrix3x2::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            Matrix3x2::from_cols(
+                Vector2::new(1.0f64, 2.0),
+                Vector2::new(3.0, 4.0),
+                Vector2::new(5.0, 6.0)
+            ),
+            A
+        );
+        assert_eq!(A.row(0), Vector3::new(1.0f64, 3.0, 5.0));
+        assert_eq!(A.row(1), Vector3::new(2.0f64, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t: Matrix2x3<f64> = A.transpose();
+        assert_eq!(t, Matrix2x3::new(1.0f64, 3.0, 5.0, 2.0, 4.0, 6.0));
+        assert_eq!(t.transpose(), A);
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(
+            A * Vector3::new(1.0f64, 0.0, -1.0),
+            Vector2::new(-4.0f64, -4.0)
+        );
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        let aat: Matrix2<f64> = A * A.transpose();
+        assert_eq!(aat, Matrix2::new(35.0f64, 44.0, 44.0, 56.0));
+
+        let ata: Matrix3<f64> = A.transpose() * A;
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        assert_eq!(ata, Matrix3::new(
+            5.0f64, 11.0, 17.0,
+            11.0, 25.0, 39.0,
+            17.0, 39.0, 61.0,
+        ));
+
+        assert_eq!(A * Matrix3::identity(), A);
+        assert_eq!(Matrix2::identity() * A, A);
+    }
+}
+
+pub mod matrix2x4 {
+    use cgmath::*;
+
+    const A: Matrix2x4<f64> = Matrix2x4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            Matrix2x4::from_cols(
+                Vector4::new(1.0f64, 2.0, 3.0, 4.0),
+                Vector4::new(5.0, 6.0, 7.0, 8.0)
+            ),
+            A
+        );
+        assert_eq!(A.row(3), Vector2::new(4.0f64, 8.0));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t: Matrix4x2<f64> = A.transpose();
+        assert_eq!(t, Matrix4x2::new(1.0f64, 5.0, 2.0, 6.0, 3.0, 7.0, 4.0, 8.0));
+        assert_eq!(t.transpose(), A);
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(
+            A * Vector2::new(1.0f64, -1.0),
+            Vector4::new(-4.0f64, -4.0, -4.0, -4.0)
+        );
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        let t = A.transpose();
+        let ata: Matrix2<f64> = t * A;
+        assert_eq!(ata, Matrix2::new(30.0f64, 70.0, 70.0, 174.0));
+
+        let aat: Matrix4<f64> = A * t;
+        assert_eq!(aat, Matrix4::from_cols(A * t.x, A * t.y, A * t.z, A * t.w));
+        assert_eq!(aat[0][0], 26.0);
+
+        assert_eq!(A * Matrix2::identity(), A);
+        assert_eq!(Matrix4::identity() * A, A);
+    }
+}
+
+pub mod matrix4x2 {
+    use cgmath::*;
+
+    const A: Matrix4x2<f64> = Matrix4x2::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            Matrix4x2::from_cols(
+                Vector2::new(1.0f64, 2.0),
+                Vector2::new(3.0, 4.0),
+                Vector2::new(5.0, 6.0),
+                Vector2::new(7.0, 8.0)
+            ),
+            A
+        );
+        assert_eq!(A.row(1), Vector4::new(2.0f64, 4.0, 6.0, 8.0));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t: Matrix2x4<f64> = A.transpose();
+        assert_eq!(t, Matrix2x4::new(1.0f64, 3.0, 5.0, 7.0, 2.0, 4.0, 6.0, 8.0));
+        assert_eq!(t.transpose(), A);
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(
+            A * Vector4::new(1.0f64, 0.0, 0.0, -1.0),
+            Vector2::new(-6.0f64, -6.0)
+        );
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        let aat: Matrix2<f64> = A * A.transpose();
+        assert_eq!(aat, Matrix2::new(84.0f64, 100.0, 100.0, 120.0));
+
+        assert_eq!(A * Matrix4::identity(), A);
+        assert_eq!(Matrix2::identity() * A, A);
+    }
+}
+
+pub mod matrix4x3 {
+    use cgmath::*;
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const A: Matrix4x3<f64> = Matrix4x3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 9.0,
+        10.0, 11.0, 12.0,
+    );
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            Matrix4x3::from_cols(
+                Vector3::new(1.0f64, 2.0, 3.0),
+                Vector3::new(4.0, 5.0, 6.0),
+                Vector3::new(7.0, 8.0, 9.0),
+                Vector3::new(10.0, 11.0, 12.0)
+            ),
+            A
+        );
+        assert_eq!(A.row(2), Vector4::new(3.0f64, 6.0, 9.0, 12.0));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t: Matrix3x4<f64> = A.transpose();
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        assert_eq!(t, Matrix3x4::new(
+            1.0f64, 4.0, 7.0, 10.0,
+            2.0, 5.0, 8.0, 11.0,
+            3.0, 6.0, 9.0, 12.0,
+        ));
+        assert_eq!(t.transpose(), A);
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(
+            A * Vector4::new(1.0f64, 0.0, 0.0, -1.0),
+            Vector3::new(-9.0f64, -9.0, -9.0)
+        );
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        let t = A.transpose();
+        let aat: Matrix3<f64> = A * t;
+        assert_eq!(aat, Matrix3::from_cols(A * t.x, A * t.y, A * t.z));
+
+        let b: Matrix2x4<f64> = Matrix2x4::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(A * b, Matrix2x3::from_cols(A.x, A.w));
+
+        assert_eq!(A * Matrix4::identity(), A);
+        assert_eq!(Matrix3::identity() * A, A);
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::packing::*;
+use cgmath::*;
+use std::f32;
+use std::f64;
+
+#[test]
+#[cfg(feature = "const-generics")]
+fn test_normalized() {
+    let v = Vector4::new(0.0f32, 1.0, 0.5, -0.25);
+    assert_eq!(pack_unorm8(v), [0, 255, 128, 0]);
+    assert_eq!(pack_unorm16(v), [0, 65535, 32768, 0]);
+    assert_eq!(pack_snorm8(v), [0, 127, 64, -32]);
+    assert_eq!(pack_snorm16(v), [0, 32767, 16384, -8192]);
+
+    // Out of range values are clamped, and NaN becomes zero.
+    let v = Vector3::new(2.0f32, -2.0, f32::NAN);
+    assert_eq!(pack_unorm8(v), [255, 0, 0]);
+    assert_eq!(pack_snorm8(v), [127, -127, 0]);
+    assert_eq!(pack_snorm16(v), [32767, -32767, 0]);
+
+    let u: Vector2<f32> = unpack_unorm8([0, 51]);
+    assert_eq!(u, Vector2::new(0.0, 0.2));
+    let s: Vector2<f32> = unpack_snorm8([-128, -127]);
+    assert_eq!(s, Vector2::new(-1.0, -1.0));
+    let s: Vector2<f32> = unpack_snorm16([-32768, 32767]);
+    assert_eq!(s, Vector2::new(-1.0, 1.0));
+
+    // Every value round-trips.
+    for i in 0..=255u8 {
+        let u: [f32; 1] = unpack_unorm8([i]);
+        assert_eq!(pack_unorm8(u), [i]);
+    }
+    for i in -127..=127i8 {
+        let s: [f32; 1] = unpack_snorm8([i]);
+        assert_eq!(pack_snorm8(s), [i]);
+    }
+    for i in (0..=65535u16).step_by(7) {
+        let u: [f32; 1] = unpack_unorm16([i]);
+        assert_eq!(pack_unorm16(u), [i]);
+    }
+    for i in (-32767..=32767i16).step_by(7) {
+        let s: [f32; 1] = unpack_snorm16([i]);
+        assert_eq!(pack_snorm16(s), [i]);
+    }
+}
+
+#[test]
+#[cfg(feature = "const-generics")]
+fn test_half() {
+    let v = Vector4::new(1.0f32, -2.0, 0.5, 65504.0);
+    assert_eq!(pack_half(v), [0x3c00, 0xc000, 0x3800, 0x7bff]);
+    let u: Vector4<f32> = unpack_half(pack_half(v));
+    assert_eq!(u, v);
+
+    // Rounding to nearest, with ties to even.
+    let one_ulp = 1.0 / 1024.0;
+    assert_eq!(pack_half([1.0 + one_ulp * 0.5]), [0x3c00]);
+    assert_eq!(pack_half([1.0 + one_ulp * 1.5]), [0x3c02]);
+    assert_eq!(pack_half([1.0 + one_ulp * 0.51]), [0x3c01]);
+    assert_eq!(pack_half([2047.5f32]), [0x6800]);
+
+    // Overflow, subnormals and special values.
+    assert_eq!(pack_half([65520.0f32, -1.0e10]), [0x7c00, 0xfc00]);
+    assert_eq!(pack_half([65519.0f32]), [0x7bff]);
+    assert_eq!(pack_half([f32::INFINITY, -0.0]), [0x7c00, 0x8000]);
+    assert_eq!(
+        pack_half([2.0f32.powi(-24), 2.0f32.powi(-25)]),
+        [0x0001, 0x0000]
+    );
+    assert_eq!(pack_half([2.0f32.powi(-24) * 1.5]), [0x0002]);
+    assert_eq!(pack_half([2.0f32.powi(-14) * 0.99999]), [0x0400]);
+    assert_eq!(pack_half([1.0e-30f32]), [0x0000]);
+    let [nan] = pack_half([f32::NAN]);
+    assert!(nan & 0x7c00 == 0x7c00 && nan & 0x3ff != 0);
+
+    // Every finite half round-trips.
+    for bits in 0..=0xffffu16 {
+        let [f]: [f32; 1] = unpack_half([bits]);
+        if f.is_nan() {
+            continue;
+        }
+        assert_eq!(pack_half([f]), [bits]);
+    }
+    let [tiny]: [f32; 1] = unpack_half([0x0001]);
+    assert_eq!(tiny, 2.0f32.powi(-24));
+}
+
+#[test]
+fn test_10_10_10_2() {
+    let v = Vector4::new(1.0f32, 0.0, 0.5, 1.0);
+    let packed = pack_unorm10_10_10_2(v);
+    assert_eq!(packed, 0x3ff | 0x200 << 20 | 0x3 << 30);
+    assert_eq!(
+        unpack_unorm10_10_10_2(packed),
+        Vector4::new(1.0, 0.0, 512.0 / 1023.0, 1.0)
+    );
+    assert_eq!(
+        unpack_unorm10_10_10_2(pack_unorm10_10_10_2(Vector4::new(0.2, 0.4, 0.6, 0.34))),
+        Vector4::new(205.0 / 1023.0, 409.0 / 1023.0, 614.0 / 1023.0, 1.0 / 3.0)
+    );
+
+    let v = Vector4::new(-1.0f32, 1.0, 0.0, -1.0);
+    let packed = pack_snorm10_10_10_2(v);
+    assert_eq!(packed, 0x201 | 0x1ff << 10 | 0x3 << 30);
+    assert_eq!(unpack_snorm10_10_10_2(packed), v);
+    // The most negative values also unpack to -1.
+    assert_eq!(
+        unpack_snorm10_10_10_2(0x200 | 0x2 << 30),
+        Vector4::new(-1.0, 0.0, 0.0, -1.0)
+    );
+    let v = Vector4::new(0.25f32, -0.5, 0.75, 1.0);
+    assert_relative_eq!(
+        unpack_snorm10_10_10_2(pack_snorm10_10_10_2(v)),
+        v,
+        epsilon = 1.0 / 511.0
+    );
+}
+
+#[test]
+fn test_float11_11_10() {
+    let v = Vector3::new(1.0f32, 0.5, 2.0);
+    let packed = pack_float11_11_10(v);
+    assert_eq!(packed, 0x3c0 | 0x380 << 11 | 0x200 << 22);
+    assert_eq!(unpack_float11_11_10(packed), v);
+
+    // Negative values become zero, and large values saturate.
+    let packed = pack_float11_11_10(Vector3::new(-1.0, 1.0e10, f32::INFINITY));
+    assert_eq!(packed, 0x7bf << 11 | 0x3e0 << 22);
+    assert_eq!(
+        unpack_float11_11_10(packed),
+        Vector3::new(0.0, 65024.0, f32::INFINITY)
+    );
+    assert!(
+        unpack_float11_11_10(pack_float11_11_10(Vector3::new(f32::NAN, 0.0, 0.0)))
+            .x
+            .is_nan()
+    );
+
+    // Rounding to nearest, with ties to even.
+    assert_eq!(
+        pack_float11_11_10(Vector3::new(1.0 + 1.0 / 128.0, 0.0, 0.0)),
+        0x3c0
+    );
+    assert_eq!(
+        pack_float11_11_10(Vector3::new(1.0 + 3.0 / 128.0, 0.0, 0.0)),
+        0x3c2
+    );
+    assert_eq!(
+        pack_float11_11_10(Vector3::new(0.0, 0.0, 1.0 + 1.0 / 64.0)),
+        0x1e0 << 22
+    );
+    assert_eq!(
+        pack_float11_11_10(Vector3::new(0.0, 0.0, 1.0 + 3.0 / 64.0)),
+        0x1e2 << 22
+    );
+
+    // Every finite value round-trips.
+    for bits in 0..0x7c0u32 {
+        let v = unpack_float11_11_10(bits | bits << 11 | (bits >> 1) << 22);
+        assert_eq!(pack_float11_11_10(v), bits | bits << 11 | (bits >> 1) << 22);
+    }
+}
+
+#[test]
+fn test_octahedral() {
+    let axes = [
+        Vector3::unit_x(),
+        -Vector3::unit_x(),
+        Vector3::unit_y(),
+        -Vector3::unit_y(),
+        Vector3::unit_z(),
+        -Vector3::unit_z(),
+    ];
+    for &v in axes.iter() {
+        assert_eq!(decode_octahedral::<f64>(encode_octahedral(v)), v);
+    }
+    assert_eq!(
+        encode_octahedral(-Vector3::<f64>::unit_z()),
+        Vector2::new(1.0, 1.0)
+    );
+
+    for i in 0..1000 {
+        let v = spherical_fibonacci::<f64>(i, 1000);
+        let e = encode_octahedral(v);
+        assert!(e.x.abs() <= 1.0 && e.y.abs() <= 1.0);
+        assert_relative_eq!(decode_octahedral(e), v, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_octahedral_quantized() {
+    let angle = |a: Vector3<f32>, b: Vector3<f32>| Deg::from(a.angle(b)).0;
+    let (mut max8, mut max16) = (0.0f32, 0.0f32);
+    for i in 0..10000 {
+        let v = spherical_fibonacci::<f32>(i, 10000);
+        let error8 = angle(unpack_octahedral_snorm8(pack_octahedral_snorm8(v)), v);
+        let error16 = angle(unpack_octahedral_snorm16(pack_octahedral_snorm16(v)), v);
+        max8 = max8.max(error8);
+        max16 = max16.max(error16);
+
+        // The precise encoding is never worse than rounding each component.
+        let rounded = decode_octahedral(encode_octahedral(v).map(|x| (x * 127.0).round() / 127.0));
+        assert!(error8 <= angle(rounded, v) + 1.0e-6);
+    }
+    assert!(max8 < 0.65, "{}", max8);
+    assert!(max16 < 0.0075, "{}", max16);
+
+    assert_eq!(pack_octahedral_snorm16(Vector3::unit_z()), [0, 0]);
+    assert_eq!(pack_octahedral_snorm8(-Vector3::unit_z()), [127, 127]);
+    assert_eq!(unpack_octahedral_snorm8([127, 127]), -Vector3::unit_z());
+}
+
+#[test]
+fn test_spherical_fibonacci() {
+    for &n in [1u32, 2, 7, 100, 1000].iter() {
+        for i in 0..n {
+            let v = spherical_fibonacci::<f64>(i, n);
+            assert_ulps_eq!(v.magnitude(), 1.0);
+            assert_eq!(inverse_spherical_fibonacci(v, n), i);
+        }
+    }
+    for i in (0..1_000_000).step_by(997) {
+        let v = spherical_fibonacci::<f32>(i, 1_000_000);
+        assert_eq!(inverse_spherical_fibonacci(v, 1_000_000), i);
+    }
+
+    // The nearest point, compared to a brute force search.
+    let n = 500;
+    let points: Vec<Vector3<f64>> = (0..n).map(|i| spherical_fibonacci(i, n)).collect();
+    for i in 0..2000 {
+        let v = spherical_fibonacci::<f64>(i, 2000);
+        let index = inverse_spherical_fibonacci(v, n) as usize;
+        let nearest = points
+            .iter()
+            .map(|p| p.distance2(v))
+            .fold(f64::INFINITY, |a, b| a.min(b));
+        assert_ulps_eq!(points[index].distance2(v), nearest);
+    }
+}
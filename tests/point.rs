@@ -60,7 +60,26 @@ macro_rules! impl_test_rem {
 #[test]
 fn test_homogeneous() {
     let p = Point3::new(1.0f64, 2.0f64, 3.0f64);
-    assert_ulps_eq!(&p, &Point3::from_homogeneous(p.to_homogeneous()));
+    assert_ulps_eq!(&p, &Point3::from_homogeneous(p.to_homogeneous()).unwrap());
+    assert_eq!(
+        Point3::from_homogeneous(Vector4::new(2.0, 4.0, 6.0, 2.0)),
+        Some(Point3::new(1.0, 2.0, 3.0))
+    );
+    assert_eq!(Point3::from_homogeneous(Vector4::new(1.0, 2.0, 3.0, 0.0)), None);
+
+    let p = Point2::new(1.0f64, 2.0f64);
+    assert_eq!(p.to_homogeneous(), Vector3::new(1.0, 2.0, 1.0));
+    assert_eq!(Point2::from_homogeneous(p.to_homogeneous()), Some(p));
+    assert_eq!(
+        Point2::from_homogeneous(Vector3::new(3.0, -6.0, -3.0)),
+        Some(Point2::new(-1.0, 2.0))
+    );
+    assert_eq!(Point2::from_homogeneous(Vector3::new(1.0, 2.0, 0.0)), None);
+
+    let p = Point1::new(5);
+    assert_eq!(p.to_homogeneous(), Vector2::new(5, 1));
+    assert_eq!(Point1::from_homogeneous(Vector2::new(10, 2)), Some(p));
+    assert_eq!(Point1::from_homogeneous(Vector2::new(10, 0)), None);
 }
 
 #[test]
@@ -93,3 +112,18 @@ fn test_cast() {
         Point3::new(1.0f32, 2.4, -3.13)
     );
 }
+
+#[test]
+fn test_component_wise() {
+    let p = Point3::new(-1.5f64, 0.25, 2.0);
+    assert_eq!(p.floor(), Point3::new(-2.0, 0.0, 2.0));
+    assert_eq!(p.fract(), Point3::new(0.5, 0.25, 0.0));
+    assert_eq!(
+        p.max(Point3::new(0.0, 0.0, 0.0)),
+        Point3::new(0.0, 0.25, 2.0)
+    );
+    assert_eq!(p.max_element(), 2.0);
+    assert_eq!(p.argmin(), 0);
+    assert_eq!(Point2::new(3, 1).min_element(), 1);
+    assert_eq!(Point1::new(-2.0f32).abs(), Point1::new(2.0));
+}
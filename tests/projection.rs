@@ -13,9 +13,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate approx;
 extern crate cgmath;
 
-use cgmath::{ortho, Matrix4, Vector4};
+use cgmath::camera::{halton, jitter, oblique_near_plane, HaltonJitter};
+use cgmath::*;
+use std::f64;
 
 #[test]
 fn test_ortho_scale() {
@@ -66,3 +69,570 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+fn project(m: Matrix4<f64>, p: Point3<f64>) -> Point3<f64> {
+    m.project_point(p).unwrap()
+}
+
+// Checks a projection of the view volume with `right = top = near = 1` and
+// `far = 10`, where the far plane has the given half extent.
+fn check_clip_space(clip_space: ClipSpace, m: Matrix4<f64>, far_extent: f64) {
+    let forward = match clip_space.handedness {
+        Handedness::Right => -1.0,
+        Handedness::Left => 1.0,
+    };
+    let (mut near_depth, mut far_depth) = match clip_space.depth_range {
+        DepthRange::NegativeOneToOne => (-1.0, 1.0),
+        DepthRange::ZeroToOne => (0.0, 1.0),
+    };
+    if clip_space.reverse_z {
+        std::mem::swap(&mut near_depth, &mut far_depth);
+    }
+    let top = match clip_space.y_axis {
+        YAxis::Up => 1.0,
+        YAxis::Down => -1.0,
+    };
+
+    assert_ulps_eq!(
+        project(m, Point3::new(0.0, 0.0, forward)),
+        Point3::new(0.0, 0.0, near_depth)
+    );
+    assert_ulps_eq!(
+        project(m, Point3::new(1.0, 1.0, forward)),
+        Point3::new(1.0, top, near_depth)
+    );
+    assert_ulps_eq!(
+        project(m, Point3::new(-far_extent, far_extent, forward * 10.0)),
+        Point3::new(-1.0, top, far_depth)
+    );
+}
+
+#[test]
+fn test_clip_space_conventions() {
+    let clip_spaces = [
+        ClipSpace::OPENGL,
+        ClipSpace::VULKAN,
+        ClipSpace::DIRECT3D,
+        ClipSpace::METAL,
+        ClipSpace::WEBGPU,
+        ClipSpace {
+            depth_range: DepthRange::NegativeOneToOne,
+            reverse_z: false,
+            y_axis: YAxis::Down,
+            handedness: Handedness::Left,
+        },
+    ];
+    for &clip_space in clip_spaces.iter() {
+        for &clip_space in [clip_space, clip_space.reversed()].iter() {
+            let perspective = clip_space.perspective(Deg(90.0), 1.0, 1.0, 10.0);
+            let frustum = clip_space.frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+            let ortho = clip_space.ortho(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+            check_clip_space(clip_space, perspective, 10.0);
+            check_clip_space(clip_space, frustum, 10.0);
+            check_clip_space(clip_space, ortho, 1.0);
+        }
+    }
+}
+
+fn clip_spaces() -> Vec<ClipSpace> {
+    let mut clip_spaces = Vec::new();
+    for &depth_range in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne].iter() {
+        for &reverse_z in [false, true].iter() {
+            for &y_axis in [YAxis::Up, YAxis::Down].iter() {
+                for &handedness in [Handedness::Right, Handedness::Left].iter() {
+                    clip_spaces.push(ClipSpace {
+                        depth_range: depth_range,
+                        reverse_z: reverse_z,
+                        y_axis: y_axis,
+                        handedness: handedness,
+                    });
+                }
+            }
+        }
+    }
+    clip_spaces
+}
+
+#[test]
+fn test_infinite_far() {
+    for clip_space in clip_spaces() {
+        let m = clip_space.perspective(Deg(90.0), 1.0, 1.0, f64::INFINITY);
+        assert!(m.is_finite());
+
+        // The infinite projection is the limit of ever further far planes
+        let far = clip_space.perspective(Deg(90.0), 1.0, 1.0, 1.0e12);
+        assert_relative_eq!(m, far, epsilon = 1e-9);
+
+        let forward = match clip_space.handedness {
+            Handedness::Right => -1.0,
+            Handedness::Left => 1.0,
+        };
+        let far_depth = match (clip_space.depth_range, clip_space.reverse_z) {
+            (_, false) => 1.0,
+            (DepthRange::NegativeOneToOne, true) => -1.0,
+            (DepthRange::ZeroToOne, true) => 0.0,
+        };
+        let depth = project(m, Point3::new(0.0, 0.0, forward * 1.0e300)).z;
+        assert_ulps_eq!(depth, far_depth);
+    }
+}
+
+#[test]
+fn test_reverse_z() {
+    let m = ClipSpace::DIRECT3D
+        .reversed()
+        .perspective(Deg(60.0f64), 1.0, 0.1, f64::INFINITY);
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let expected = Matrix4::new(
+        3.0f64.sqrt(), 0.0, 0.0, 0.0,
+        0.0, 3.0f64.sqrt(), 0.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+        0.0, 0.0, 0.1, 0.0,
+    );
+    assert_ulps_eq!(m, expected);
+}
+
+#[test]
+fn test_perspective_inverse() {
+    let persp = Perspective {
+        left: -1.0f64,
+        right: 2.0,
+        bottom: -0.5,
+        top: 1.5,
+        near: 0.5,
+        far: 50.0,
+    };
+    let persp_fov = PerspectiveFov {
+        fovy: Rad(1.2f64),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    for clip_space in clip_spaces() {
+        for &far in [50.0, f64::INFINITY].iter() {
+            let persp = Perspective { far: far, ..persp };
+            let m = persp.to_matrix(clip_space);
+            assert_relative_eq!(
+                m * persp.inverse(clip_space),
+                Matrix4::identity(),
+                epsilon = 1e-12
+            );
+
+            let persp_fov = PerspectiveFov { far: far, ..persp_fov };
+            let m = persp_fov.to_matrix(clip_space);
+            assert_relative_eq!(
+                m * persp_fov.inverse(clip_space),
+                Matrix4::identity(),
+                epsilon = 1e-12
+            );
+        }
+    }
+}
+
+#[test]
+fn test_clip_space_opengl_default() {
+    assert_eq!(ClipSpace::default(), ClipSpace::OPENGL);
+    assert_eq!(
+        ClipSpace::OPENGL.perspective(Deg(60.0f32), 1.5, 0.1, 100.0),
+        perspective(Deg(60.0f32), 1.5, 0.1, 100.0)
+    );
+    assert_eq!(
+        ClipSpace::OPENGL.frustum(-1.0f32, 2.0, -3.0, 4.0, 0.5, 50.0),
+        frustum(-1.0f32, 2.0, -3.0, 4.0, 0.5, 50.0)
+    );
+    assert_eq!(
+        ClipSpace::OPENGL.ortho(-1.0f32, 2.0, -3.0, 4.0, 0.5, 50.0),
+        ortho(-1.0f32, 2.0, -3.0, 4.0, 0.5, 50.0)
+    );
+}
+
+#[test]
+fn test_clip_space_correction() {
+    // The usual correction matrices applied to an OpenGL projection
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let to_zero_to_one = Matrix4::new(
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 0.5, 0.0,
+        0.0, 0.0, 0.5, 1.0,
+    );
+    let flip_y = Matrix4::from_nonuniform_scale(1.0, -1.0, 1.0);
+    let flip_z = Matrix4::from_nonuniform_scale(1.0, 1.0, -1.0);
+
+    let opengl = perspective(Deg(60.0f64), 1.5, 0.1, 100.0);
+    assert_ulps_eq!(
+        ClipSpace::WEBGPU.perspective(Deg(60.0f64), 1.5, 0.1, 100.0),
+        to_zero_to_one * opengl
+    );
+    assert_ulps_eq!(
+        ClipSpace::VULKAN.perspective(Deg(60.0f64), 1.5, 0.1, 100.0),
+        flip_y * to_zero_to_one * opengl
+    );
+    assert_ulps_eq!(
+        ClipSpace::DIRECT3D.perspective(Deg(60.0f64), 1.5, 0.1, 100.0),
+        to_zero_to_one * opengl * flip_z
+    );
+}
+
+#[test]
+fn test_ortho_inverse() {
+    let o = Ortho {
+        left: -1.0f64,
+        right: 3.0,
+        bottom: -2.0,
+        top: 0.5,
+        near: -1.0,
+        far: 20.0,
+    };
+    for clip_space in clip_spaces() {
+        assert_relative_eq!(
+            o.to_matrix(clip_space) * o.inverse(clip_space),
+            Matrix4::identity(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_linearize_depth() {
+    let persp_fov = PerspectiveFov {
+        fovy: Rad(1.2f64),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    let persp = persp_fov.to_perspective();
+    let o = Ortho {
+        left: -1.0f64,
+        right: 1.0,
+        bottom: -1.0,
+        top: 1.0,
+        near: 0.1,
+        far: 100.0,
+    };
+    for clip_space in clip_spaces() {
+        let forward = match clip_space.handedness {
+            Handedness::Right => -1.0,
+            Handedness::Left => 1.0,
+        };
+        let to_depth_buffer = |depth: f64| match clip_space.depth_range {
+            DepthRange::NegativeOneToOne => (depth + 1.0) / 2.0,
+            DepthRange::ZeroToOne => depth,
+        };
+        for &distance in [0.1, 0.5, 3.0, 42.0, 100.0].iter() {
+            let p = Point3::new(0.25, -0.5, forward * distance);
+
+            let depth = project(persp_fov.to_matrix(clip_space), p).z;
+            assert_relative_eq!(persp_fov.linearize_depth(clip_space, depth), p.z, epsilon = 1e-9);
+            assert_relative_eq!(
+                persp_fov.linearize_depth_buffer(clip_space, to_depth_buffer(depth)),
+                p.z,
+                epsilon = 1e-9
+            );
+
+            let depth = project(persp.to_matrix(clip_space), p).z;
+            assert_relative_eq!(persp.linearize_depth(clip_space, depth), p.z, epsilon = 1e-9);
+
+            let depth = project(o.to_matrix(clip_space), p).z;
+            assert_relative_eq!(o.linearize_depth(clip_space, depth), p.z, epsilon = 1e-12);
+            assert_relative_eq!(
+                o.linearize_depth_buffer(clip_space, to_depth_buffer(depth)),
+                p.z,
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    // A depth buffer value of 0.5 with `near = 1` and an infinite far plane
+    let persp = PerspectiveFov {
+        far: f64::INFINITY,
+        near: 1.0,
+        ..persp_fov
+    };
+    assert_eq!(persp.linearize_depth_buffer(ClipSpace::OPENGL, 0.5), -2.0);
+    assert_eq!(persp.linearize_depth_buffer(ClipSpace::VULKAN, 0.5), -2.0);
+}
+
+#[test]
+fn test_from_matrix() {
+    let persp_fov = PerspectiveFov {
+        fovy: Rad(1.2f64),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    let persp = Perspective {
+        left: -0.3f64,
+        right: 0.1,
+        bottom: -0.2,
+        top: 0.15,
+        near: 0.1,
+        far: 100.0,
+    };
+    let o = Ortho {
+        left: -3.0f64,
+        right: 1.0,
+        bottom: -2.0,
+        top: 4.0,
+        near: -1.0,
+        far: 20.0,
+    };
+
+    for clip_space in clip_spaces() {
+        for &far in [100.0, f64::INFINITY].iter() {
+            let m = PerspectiveFov { far: far, ..persp_fov }.to_matrix(clip_space);
+            let p = PerspectiveFov::from_matrix(m * 3.0, clip_space).unwrap();
+            assert_relative_eq!(p.fovy, persp_fov.fovy, epsilon = 1e-12);
+            assert_relative_eq!(p.aspect, persp_fov.aspect, epsilon = 1e-12);
+            assert_relative_eq!(p.near, persp_fov.near, epsilon = 1e-12);
+            assert_relative_eq!(p.far, far, max_relative = 1e-9);
+
+            let m = Perspective { far: far, ..persp }.to_matrix(clip_space);
+            let p = Perspective::from_matrix(m, clip_space).unwrap();
+            assert_relative_eq!(p.left, persp.left, epsilon = 1e-12);
+            assert_relative_eq!(p.right, persp.right, epsilon = 1e-12);
+            assert_relative_eq!(p.bottom, persp.bottom, epsilon = 1e-12);
+            assert_relative_eq!(p.top, persp.top, epsilon = 1e-12);
+            assert_relative_eq!(p.near, persp.near, epsilon = 1e-12);
+            assert_relative_eq!(p.far, far, max_relative = 1e-9);
+        }
+
+        let p = Ortho::from_matrix(o.to_matrix(clip_space), clip_space).unwrap();
+        assert_relative_eq!(p.left, o.left, epsilon = 1e-12);
+        assert_relative_eq!(p.right, o.right, epsilon = 1e-12);
+        assert_relative_eq!(p.bottom, o.bottom, epsilon = 1e-12);
+        assert_relative_eq!(p.top, o.top, epsilon = 1e-12);
+        assert_relative_eq!(p.near, o.near, epsilon = 1e-12);
+        assert_relative_eq!(p.far, o.far, epsilon = 1e-12);
+    }
+}
+
+#[test]
+#[cfg(has_try_from)]
+fn test_from_matrix_detection() {
+    use std::convert::TryFrom;
+
+    let m = perspective(Deg(60.0f64), 1.0, 0.1, 100.0);
+    match Projection::try_from(m) {
+        Ok(Projection::PerspectiveFov(p)) => assert_relative_eq!(p.fovy, Deg(60.0).into()),
+        other => panic!("expected a symmetric perspective, found {:?}", other),
+    }
+    assert!(Perspective::try_from(m).is_ok());
+    assert_eq!(Ortho::try_from(m), Err(ProjectionError::Unrecognized));
+
+    let m = frustum(-1.0f64, 2.0, -1.0, 1.0, 0.5, 10.0);
+    match Projection::try_from(m) {
+        Ok(Projection::Perspective(p)) => assert_relative_eq!(p.right, 2.0),
+        other => panic!("expected an off-center perspective, found {:?}", other),
+    }
+    assert_eq!(PerspectiveFov::try_from(m), Err(ProjectionError::OffCenter));
+
+    let m = ortho(-1.0f64, 1.0, -1.0, 1.0, 0.5, 10.0);
+    match Projection::try_from(m) {
+        Ok(Projection::Ortho(o)) => assert_relative_eq!(o.far, 10.0),
+        other => panic!("expected an orthographic projection, found {:?}", other),
+    }
+
+    let m = Matrix4::from_angle_x(Deg(30.0f64));
+    assert_eq!(Projection::try_from(m), Err(ProjectionError::Unrecognized));
+    assert_eq!(Projection::try_from(Matrix4::<f64>::zero()), Err(ProjectionError::Unrecognized));
+
+    // A left-handed matrix is not a valid right-handed projection.
+    let m = ClipSpace::DIRECT3D.perspective(Deg(60.0f64), 1.0, 0.1, 100.0);
+    assert_eq!(Projection::try_from(m), Err(ProjectionError::InvalidParameters));
+    assert!(Projection::from_matrix(m, ClipSpace::DIRECT3D).is_ok());
+}
+
+#[test]
+fn test_from_matrix_small_offset() {
+    // A frustum that is off-center by much less than its size, as for a tile
+    // of a large image, must not be mistaken for a rounding error.
+    let m = frustum(-0.01f64, 0.01 + 2e-11, -0.01, 0.01, 0.01, 10.0);
+    match Projection::from_matrix(m, ClipSpace::OPENGL) {
+        Ok(Projection::Perspective(p)) => {
+            assert_relative_eq!(p.left, -0.01, epsilon = 1e-15);
+            assert_relative_eq!(p.right, 0.01 + 2e-11, epsilon = 1e-15);
+        }
+        other => panic!("expected an off-center perspective, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_from_matrix_rounding_errors() {
+    let persp_fov = PerspectiveFov {
+        fovy: Rad(1.2f64),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    let mut m = persp_fov.to_matrix(ClipSpace::OPENGL);
+    m.x.y = 1e-17;
+    m.y.w = -1e-16;
+    m.z.x = 1e-15;
+    m.w.w = 1e-17;
+    match Projection::from_matrix(m, ClipSpace::OPENGL) {
+        Ok(Projection::PerspectiveFov(p)) => assert_relative_eq!(
+            p.to_matrix(ClipSpace::OPENGL),
+            persp_fov.to_matrix(ClipSpace::OPENGL),
+            epsilon = 1e-9
+        ),
+        other => panic!("expected a symmetric perspective, found {:?}", other),
+    }
+
+    // Recover the projection from a combined view projection matrix.
+    let view = Matrix4::look_at_rh(
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(-2.0, 0.5, 0.0),
+        Vector3::unit_y(),
+    );
+    let view_proj = persp_fov.to_matrix(ClipSpace::VULKAN) * view;
+    let m = view_proj * view.invert().unwrap();
+    let p = PerspectiveFov::from_matrix(m, ClipSpace::VULKAN).unwrap();
+    assert_relative_eq!(p.fovy, persp_fov.fovy, epsilon = 1e-9);
+    assert_relative_eq!(p.aspect, persp_fov.aspect, epsilon = 1e-9);
+    assert_relative_eq!(p.near, persp_fov.near, epsilon = 1e-9);
+    assert_relative_eq!(p.far, persp_fov.far, max_relative = 1e-6);
+
+    let o = Ortho {
+        left: -3.0f64,
+        right: 1.0,
+        bottom: -2.0,
+        top: 4.0,
+        near: -1.0,
+        far: 20.0,
+    };
+    let m = o.to_matrix(ClipSpace::DIRECT3D) * view * view.invert().unwrap();
+    let p = Ortho::from_matrix(m, ClipSpace::DIRECT3D).unwrap();
+    assert_relative_eq!(
+        p.to_matrix(ClipSpace::DIRECT3D),
+        o.to_matrix(ClipSpace::DIRECT3D),
+        epsilon = 1e-9
+    );
+
+    // Errors that are not small compared to the matrix are still rejected.
+    let mut m = persp_fov.to_matrix(ClipSpace::OPENGL);
+    m.x.y = 1e-4;
+    assert_eq!(
+        Projection::from_matrix(m, ClipSpace::OPENGL),
+        Err(ProjectionError::Unrecognized)
+    );
+}
+
+#[test]
+fn test_asymmetric_fov() {
+    let fov = AsymmetricFov::from_tangents(-1.2f64, 0.8, 1.0, -0.9, 0.1, 100.0);
+    assert_relative_eq!(Rad::tan(fov.left), -1.2);
+    assert_relative_eq!(
+        Matrix4::from(fov),
+        frustum(-0.12, 0.08, -0.09, 0.1, 0.1, 100.0),
+        epsilon = 1e-12
+    );
+
+    // A symmetric field of view matches `PerspectiveFov`.
+    let persp_fov = PerspectiveFov {
+        fovy: Rad(1.2f64),
+        aspect: 1.0,
+        near: 0.1,
+        far: f64::INFINITY,
+    };
+    let fov = AsymmetricFov {
+        left: Rad(-0.6),
+        right: Rad(0.6),
+        up: Rad(0.6),
+        down: Rad(-0.6),
+        near: 0.1,
+        far: f64::INFINITY,
+    };
+    for clip_space in clip_spaces() {
+        assert_relative_eq!(
+            fov.to_matrix(clip_space),
+            persp_fov.to_matrix(clip_space),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            fov.to_matrix(clip_space) * fov.inverse(clip_space),
+            Matrix4::identity(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            fov.linearize_depth(clip_space, 0.5),
+            persp_fov.linearize_depth(clip_space, 0.5)
+        );
+    }
+}
+
+#[test]
+fn test_halton_jitter() {
+    assert_eq!(halton::<f64>(0, 2), 0.0);
+    assert_eq!(halton::<f64>(3, 2), 0.75);
+    assert_relative_eq!(halton::<f64>(5, 3), 7.0 / 9.0);
+
+    let offsets: Vec<Vector2<f64>> = HaltonJitter::new(4).take(5).collect();
+    assert_relative_eq!(offsets[0], Vector2::new(0.0, -1.0 / 6.0));
+    assert_relative_eq!(offsets[1], Vector2::new(-0.25, 1.0 / 6.0));
+    assert_eq!(offsets[4], offsets[0]);
+    for offset in HaltonJitter::<f64>::new(16).take(16) {
+        assert!(offset.x >= -0.5 && offset.x < 0.5);
+        assert!(offset.y >= -0.5 && offset.y < 0.5);
+    }
+}
+
+#[test]
+fn test_jitter() {
+    let size = Vector2::new(800.0, 600.0);
+    let offset = Vector2::new(0.25, -0.5);
+    let p = Point3::new(0.3, -0.2, -4.0);
+    for m in vec![
+        perspective(Deg(60.0f64), 4.0 / 3.0, 0.1, 100.0),
+        ortho(-4.0, 4.0, -3.0, 3.0, 0.1, 100.0),
+    ] {
+        let a = project(m, p);
+        let b = project(jitter(m, offset, size), p);
+        assert_relative_eq!(b.x - a.x, 2.0 * 0.25 / 800.0, epsilon = 1e-12);
+        assert_relative_eq!(b.y - a.y, 2.0 * -0.5 / 600.0, epsilon = 1e-12);
+        assert_relative_eq!(b.z, a.z, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_oblique_near_plane() {
+    for clip_space in clip_spaces() {
+        let forward = match clip_space.handedness {
+            Handedness::Right => -1.0,
+            Handedness::Left => 1.0,
+        };
+        let (near, far) = match (clip_space.depth_range, clip_space.reverse_z) {
+            (DepthRange::NegativeOneToOne, false) => (-1.0, 1.0),
+            (DepthRange::NegativeOneToOne, true) => (1.0, -1.0),
+            (DepthRange::ZeroToOne, false) => (0.0, 1.0),
+            (DepthRange::ZeroToOne, true) => (1.0, 0.0),
+        };
+
+        // A tilted plane through a point in front of the eye, facing away
+        // from it.
+        let point = Vector3::new(0.0, 0.0, 2.0 * forward);
+        let normal = Vector3::new(0.0, 0.3, forward).normalize();
+        let plane = normal.extend(-normal.dot(point));
+
+        let m = clip_space.perspective(Deg(60.0f64), 4.0 / 3.0, 0.1, 100.0);
+        let oblique = oblique_near_plane(m, plane, clip_space).unwrap();
+
+        // Points on the plane are on the new near plane.
+        for &(x, y) in [(0.0, 0.0), (0.5, -0.5), (-0.3, 0.4)].iter() {
+            let z = (plane.w + plane.x * x + plane.y * y) / -plane.z;
+            let p = project(oblique, Point3::new(x, y, z));
+            assert_relative_eq!(p.z, near, epsilon = 1e-9);
+        }
+
+        // Points beyond the plane are visible, and x and y are unchanged.
+        let p = Point3::new(0.2, -0.1, 10.0 * forward);
+        let q = project(oblique, p);
+        assert!(q.z > near.min(far) && q.z < near.max(far));
+        assert_relative_eq!(q.x, project(m, p).x, epsilon = 1e-12);
+        assert_relative_eq!(q.y, project(m, p).y, epsilon = 1e-12);
+
+        // Points between the eye and the plane are clipped.
+        let q = project(oblique, Point3::new(0.0, 0.0, forward));
+        assert!(q.z < near.min(far) || q.z > near.max(far));
+    }
+}
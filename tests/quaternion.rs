@@ -283,7 +283,7 @@ mod arc {
     #[test]
     fn test_ortho() {
         let q: Quaternion<f32> = Quaternion::from_arc(Vector3::unit_x(), Vector3::unit_y(), None);
-        let q2 = Quaternion::from_axis_angle(Vector3::unit_z(), Rad::turn_div_4());
+        let q2 = Quaternion::from_axis_angle(Unit::unit_z(), Rad::turn_div_4());
         assert_ulps_eq!(q, q2);
     }
 }
@@ -374,7 +374,8 @@ mod rotate_from_axis_angle {
     fn test_xy() {
         let vec = vec3(0.0, 0.0, 1.0);
 
-        let rot = Quaternion::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), Deg(90.0));
+        let axis = Unit::try_normalize(vec3(1.0, 1.0, 0.0)).unwrap();
+        let rot = Quaternion::from_axis_angle(axis, Deg(90.0));
         assert_ulps_eq!(
             vec3(2.0f32.sqrt() / 2.0, -2.0f32.sqrt() / 2.0, 0.0),
             rot * vec
@@ -385,7 +386,8 @@ mod rotate_from_axis_angle {
     fn test_yz() {
         let vec = vec3(1.0, 0.0, 0.0);
 
-        let rot = Quaternion::from_axis_angle(vec3(0.0, 1.0, 1.0).normalize(), Deg(-90.0));
+        let axis = Unit::try_normalize(vec3(0.0, 1.0, 1.0)).unwrap();
+        let rot = Quaternion::from_axis_angle(axis, Deg(-90.0));
         assert_ulps_eq!(
             vec3(0.0, -2.0f32.sqrt() / 2.0, 2.0f32.sqrt() / 2.0),
             rot * vec
@@ -396,7 +398,8 @@ mod rotate_from_axis_angle {
     fn test_xz() {
         let vec = vec3(0.0, 1.0, 0.0);
 
-        let rot = Quaternion::from_axis_angle(vec3(1.0, 0.0, 1.0).normalize(), Deg(90.0));
+        let axis = Unit::try_normalize(vec3(1.0, 0.0, 1.0)).unwrap();
+        let rot = Quaternion::from_axis_angle(axis, Deg(90.0));
         assert_ulps_eq!(
             vec3(-2.0f32.sqrt() / 2.0, 0.0, 2.0f32.sqrt() / 2.0),
             rot * vec
@@ -25,7 +25,7 @@ mod rotation {
     }
 
     pub fn a3<R: Rotation3<f64>>() -> R {
-        let axis = Vector3::new(1.0, 1.0, 0.0).normalize();
+        let axis = Unit::try_normalize(Vector3::new(1.0, 1.0, 0.0)).unwrap();
         Rotation3::from_axis_angle(axis, Deg(30.0))
     }
 }
@@ -0,0 +1,152 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use std::mem;
+
+use cgmath::space::*;
+use cgmath::*;
+
+enum Local {}
+enum World {}
+enum View {}
+
+#[test]
+fn test_points_and_vectors() {
+    let a: InSpace<_, World> = InSpace::new(Point3::new(1.0, 2.0, 3.0));
+    let b: InSpace<_, World> = InSpace::new(Point3::new(2.0, 2.0, 5.0));
+    let d: InSpace<Vector3<f64>, World> = b - a;
+    assert_eq!(d.into_inner(), Vector3::new(1.0, 0.0, 2.0));
+    assert_eq!(a + d, b);
+    assert_eq!(b - d, a);
+    assert_eq!((d * 2.0).into_inner(), Vector3::new(2.0, 0.0, 4.0));
+    assert_eq!((-d / 2.0).into_inner(), Vector3::new(-0.5, 0.0, -1.0));
+
+    let mut c = a;
+    c += d;
+    assert_eq!(c, b);
+    c -= d;
+    assert_eq!(c, a);
+
+    let x: InSpace<_, World> = InSpace::new(Vector3::unit_x());
+    let y: InSpace<_, World> = InSpace::new(Vector3::unit_y() * 2.0);
+    assert_eq!(x.dot(y), 0.0);
+    assert_eq!(y.magnitude(), 2.0);
+    assert_eq!(y.normalize().into_inner(), Vector3::unit_y());
+    assert_eq!(x.cross(y).into_inner(), Vector3::new(0.0, 0.0, 2.0));
+}
+
+#[test]
+fn test_transforms() {
+    let model: SpaceTransform<_, _, Local, World> =
+        SpaceTransform::new(Matrix4::from_translation(Vector3::new(1.0, 0.0, 0.0)));
+    let view: SpaceTransform<_, _, World, View> = SpaceTransform::new(Matrix4::from_scale(2.0));
+
+    let p: InSpace<_, Local> = InSpace::new(Point3::new(0.0, 1.0, 0.0));
+    let v: InSpace<_, Local> = InSpace::new(Vector3::new(0.0, 1.0, 0.0));
+    let world: InSpace<_, World> = model.transform_point(p);
+    assert_eq!(world.into_inner(), Point3::new(1.0, 1.0, 0.0));
+    assert_eq!(
+        model.transform_vector(v).into_inner(),
+        Vector3::new(0.0, 1.0, 0.0)
+    );
+
+    let model_view: SpaceTransform<_, _, Local, View> = view.concat(&model);
+    assert_eq!(
+        model_view.transform_point(p),
+        view.transform_point(model.transform_point(p))
+    );
+    assert_eq!(
+        model_view.transform_point(p).into_inner(),
+        Point3::new(2.0, 2.0, 0.0)
+    );
+
+    let inverse: SpaceTransform<_, _, View, Local> = model_view.inverse_transform().unwrap();
+    assert_ulps_eq!(
+        inverse
+            .transform_point(model_view.transform_point(p))
+            .into_inner(),
+        p.into_inner()
+    );
+}
+
+#[test]
+fn test_matrix3() {
+    // `Matrix3` transforms both `Point2` and `Point3`, so the point type
+    // picks which one.
+    let model: SpaceTransform<_, Point2<f64>, Local, World> =
+        SpaceTransform::new(Matrix3::from_translation(Vector2::new(1.0, 2.0)));
+    let view: SpaceTransform<_, Point2<f64>, World, View> =
+        SpaceTransform::new(Matrix3::from_nonuniform_scale(2.0, 3.0));
+
+    let p: InSpace<_, Local> = InSpace::new(Point2::new(1.0, 1.0));
+    let v: InSpace<_, Local> = InSpace::new(Vector2::new(1.0, 1.0));
+    assert_eq!(model.transform_point(p).into_inner(), Point2::new(2.0, 3.0));
+    assert_eq!(
+        model.transform_vector(v).into_inner(),
+        Vector2::new(1.0, 1.0)
+    );
+
+    let model_view = view.concat(&model);
+    assert_eq!(
+        model_view.transform_point(p).into_inner(),
+        Point2::new(4.0, 9.0)
+    );
+    assert_eq!(
+        model_view.transform_vector(v).into_inner(),
+        Vector2::new(2.0, 3.0)
+    );
+
+    let inverse = model_view.inverse_transform().unwrap();
+    assert_ulps_eq!(
+        inverse
+            .transform_point(model_view.transform_point(p))
+            .into_inner(),
+        p.into_inner()
+    );
+}
+
+#[test]
+fn test_decomposed() {
+    let model: SpaceTransform<_, _, Local, World> = SpaceTransform::new(Decomposed {
+        scale: 2.0,
+        rot: Quaternion::from_angle_z(Deg(90.0)),
+        disp: Vector3::new(0.0, 0.0, 1.0),
+    });
+    let p: InSpace<_, Local> = InSpace::new(Point3::new(1.0, 0.0, 0.0));
+    let world: InSpace<Point3<f64>, World> = model.transform_point(p);
+    assert_relative_eq!(
+        world.into_inner(),
+        Point3::new(0.0, 2.0, 1.0),
+        epsilon = 1.0e-12
+    );
+    let local: InSpace<Point3<f64>, Local> =
+        model.inverse_transform().unwrap().transform_point(world);
+    assert_relative_eq!(local.into_inner(), p.into_inner(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_zero_cost() {
+    assert_eq!(
+        mem::size_of::<InSpace<Point3<f32>, World>>(),
+        mem::size_of::<Point3<f32>>()
+    );
+    assert_eq!(
+        mem::size_of::<SpaceTransform<Matrix4<f32>, Point3<f32>, Local, World>>(),
+        mem::size_of::<Matrix4<f32>>()
+    );
+}
@@ -59,3 +59,38 @@ fn test_vector_swizzle() {
     assert_eq!(p3.yyx(), Vector3::new(2.0, 2.0, 1.0));
     assert_eq!(p4.xyxy(), Vector4::new(1.0, 2.0, 1.0, 2.0));
 }
+
+#[test]
+fn test_constant_swizzle() {
+    let p2 = Point2::new(1.0, 2.0);
+    let v3 = Vector3::new(1.0, 2.0, 3.0);
+    let v4 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(p2.xy1(), Point3::new(1.0, 2.0, 1.0));
+    assert_eq!(p2.y0(), Point2::new(2.0, 0.0));
+    assert_eq!(v3.x0z(), Vector3::new(1.0, 0.0, 3.0));
+    assert_eq!(v3.xyz0(), Vector4::new(1.0, 2.0, 3.0, 0.0));
+    assert_eq!(v3.xyz1(), v3.extend(1.0));
+    assert_eq!(v4.w10(), Vector3::new(4.0, 1.0, 0.0));
+    assert_eq!(Vector1::new(5).x01(), Vector3::new(5, 0, 1));
+}
+
+#[test]
+fn test_swizzle_setters() {
+    let mut p3 = Point3::new(1.0, 2.0, 3.0);
+    p3.set_xz(Point2::new(4.0, 5.0));
+    assert_eq!(p3, Point3::new(4.0, 2.0, 5.0));
+    p3.set_zyx(p3.xyz());
+    assert_eq!(p3, Point3::new(5.0, 2.0, 4.0));
+
+    let mut v4 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    v4.set_wx(Vector2::new(8.0, 9.0));
+    assert_eq!(v4, Vector4::new(9.0, 2.0, 3.0, 8.0));
+    v4.set_yzw(Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(v4, Vector4::new(9.0, 0.0, 0.0, 0.0));
+    v4.set_wzyx(Vector4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(v4, Vector4::new(4.0, 3.0, 2.0, 1.0));
+
+    let mut v2 = Vector2::new(1, 2);
+    v2.set_yx(v2);
+    assert_eq!(v2, Vector2::new(2, 1));
+}
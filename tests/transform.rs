@@ -61,6 +61,128 @@ fn test_look_at() {
     assert_ulps_eq!(&t.transform_point(point), &view_point);
 }
 
+#[test]
+fn test_look_at_handedness() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(-2.0f64, 0.5, -1.0);
+    let up = Vector3::unit_y();
+    let dir = center - eye;
+
+    let rh = Matrix4::look_at_rh(eye, center, up);
+    let lh = Matrix4::look_at_lh(eye, center, up);
+    assert_eq!(rh, Matrix4::look_at(eye, center, up));
+    assert_eq!(rh, Matrix4::look_to_rh(eye, dir, up));
+    assert_eq!(lh, Matrix4::look_to_lh(eye, dir, up));
+
+    // The rotations agree with the rotation part of the view matrices.
+    let rh3 = Matrix3::look_at_rh(eye, center, up);
+    let lh3 = Matrix3::look_at_lh(eye, center, up);
+    assert_ulps_eq!(rh.upper_left_3x3(), rh3);
+    assert_ulps_eq!(lh.upper_left_3x3(), lh3);
+    assert_eq!(lh3, Matrix3::look_at(dir, up));
+    assert_ulps_eq!(Matrix3::from(Quaternion::look_at_rh(eye, center, up)), rh3);
+    assert_ulps_eq!(Matrix3::from(Quaternion::look_to_lh(dir, up)), lh3);
+    assert_ulps_eq!(Matrix3::from(Basis3::look_to_rh(dir, up)), rh3);
+    assert_ulps_eq!(Matrix3::from(Basis3::look_at_lh(eye, center, up)), lh3);
+
+    let drh: Decomposed<Vector3<f64>, Quaternion<f64>> = Decomposed::look_at_rh(eye, center, up);
+    let dlh: Decomposed<Vector3<f64>, Basis3<f64>> = Decomposed::look_to_lh(eye, dir, up);
+    assert_ulps_eq!(Matrix4::from(drh), rh);
+    assert_ulps_eq!(Matrix4::from(dlh), lh);
+    assert_ulps_eq!(dlh, Transform::look_at(eye, center, up));
+
+    // Both put the center in front of the camera with x to the right and y
+    // up, so it ends up in the middle of the matching projection. Right is
+    // `dir × up` in a right-handed space, and `up × dir` in a left-handed one.
+    let right = dir.cross(up);
+    for &(view, clip_space, right) in [
+        (rh, ClipSpace::OPENGL, right),
+        (lh, ClipSpace::DIRECT3D, -right),
+    ]
+    .iter()
+    {
+        let proj = clip_space.perspective(Deg(60.0), 1.0, 0.1, 100.0);
+        let c = proj * view * center.to_homogeneous();
+        assert!(c.w > 0.0);
+        assert_relative_eq!(c.x / c.w, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(c.y / c.w, 0.0, epsilon = 1e-12);
+        assert!(c.z / c.w > 0.0 && c.z / c.w < 1.0);
+
+        let r = proj * view * (center + right).to_homogeneous();
+        assert!(r.x / r.w > 0.0);
+        let u = proj * view * (center + up).to_homogeneous();
+        assert!(u.y / u.w > 0.0);
+    }
+}
+
+#[test]
+fn test_affine_matrix4_round_trip() {
+    let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0))
+        * Matrix4::from_angle_y(Deg(30.0))
+        * Matrix4::from_nonuniform_scale(2.0, 3.0, 4.0);
+    let a = AffineMatrix3::from_matrix4(m).expect("Expected an affine matrix");
+    assert_eq!(Matrix4::from(a), m);
+    assert_eq!(a.translation(), Vector3::new(1.0, 2.0, 3.0));
+
+    let projection = perspective(Deg(60.0f64), 1.0, 0.1, 100.0);
+    assert!(AffineMatrix3::from_matrix4(projection).is_none());
+}
+
+#[test]
+fn test_affine_transform() {
+    let m = Matrix4::from_translation(Vector3::new(1.0f64, -2.0, 3.0))
+        * Matrix4::from_axis_angle(
+            Unit::try_normalize(Vector3::new(1.0f64, 2.0, 3.0)).unwrap(),
+            Deg(70.0),
+        )
+        * Matrix4::from_scale(1.5);
+    let a = AffineMatrix3::from_matrix4(m).unwrap();
+
+    let p = Point3::new(4.0f64, 5.0, -6.0);
+    let v = Vector3::new(-1.0f64, 0.5, 2.0);
+    assert_ulps_eq!(a.transform_point(p), m.transform_point(p));
+    assert_ulps_eq!(a.transform_vector(v), m.transform_vector(v));
+
+    let b = AffineMatrix3::from_linear_translation(
+        Matrix3::from_angle_z(Deg(45.0)),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    assert_ulps_eq!(Matrix4::from(a.concat(&b)), m * Matrix4::from(b));
+}
+
+#[test]
+fn test_affine_invert() {
+    let a = AffineMatrix3::from_linear_translation(
+        Matrix3::from_angle_x(Deg(20.0f64)) * Matrix3::from_nonuniform_scale(2.0, 0.5),
+        Vector3::new(6.0, -7.0, 8.0),
+    );
+    let inverse = a.inverse_transform().expect("Expected successful inversion");
+    assert_ulps_eq!(a.concat(&inverse), AffineMatrix3::one());
+    assert_ulps_eq!(
+        Matrix4::from(inverse),
+        Matrix4::from(a).invert().unwrap(),
+        epsilon = 1e-12
+    );
+
+    let singular = AffineMatrix3::from_linear_translation(Matrix3::<f64>::zero(), Vector3::zero());
+    assert!(singular.inverse_transform().is_none());
+}
+
+#[test]
+fn test_affine_layout() {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let a = AffineMatrix3::new(
+        1.0f32, 2.0, 3.0, 4.0,
+        5.0, 6.0, 7.0, 8.0,
+        9.0, 10.0, 11.0, 12.0,
+    );
+    let rows: &[[f32; 4]; 3] = a.as_ref();
+    assert_eq!(rows[1], [5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(AffineMatrix3::from(*rows), a);
+    assert_eq!(AffineMatrix3::from(Matrix4x3::from(a)), a);
+    assert_eq!(Matrix4x3::from(a).row(2), Vector4::new(9.0, 10.0, 11.0, 12.0));
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_serialize() {
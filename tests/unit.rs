@@ -0,0 +1,81 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+use cgmath::*;
+use std::f32;
+use std::f64;
+
+#[test]
+fn test_new() {
+    assert!(Unit::new(Vector2::new(0.6f32, 0.8)).is_some());
+    assert!(Unit::new(Vector3::new(1.0f64, 2.0, 3.0).normalize()).is_some());
+    assert!(Unit::new(Vector4::new(0.5f32, 0.5, 0.5, 0.5)).is_some());
+    assert!(Unit::new(Quaternion::new(1.0f64, 0.0, 0.0, 0.0)).is_some());
+
+    assert!(Unit::new(Vector3::new(0.577f32, 0.577, 0.577)).is_none());
+    assert!(Unit::new(Vector2::new(2.0f64, 0.0)).is_none());
+    assert!(Unit::new(Vector3::<f64>::zero()).is_none());
+    assert!(Unit::new(Vector3::new(f32::NAN, 1.0, 0.0)).is_none());
+}
+
+#[test]
+fn test_try_normalize() {
+    let u = Unit::try_normalize(Vector3::new(0.0f64, 3.0, 4.0)).unwrap();
+    assert_eq!(*u, Vector3::new(0.0, 0.6, 0.8));
+    assert_eq!(u.into_inner(), Vector3::new(0.0, 0.6, 0.8));
+    assert_ulps_eq!(u.magnitude(), 1.0);
+    assert_eq!((-u).into_inner(), Vector3::new(0.0, -0.6, -0.8));
+
+    let q = Unit::try_normalize(Quaternion::new(1.0f32, 1.0, 1.0, 1.0)).unwrap();
+    assert_eq!(*q, Quaternion::new(0.5, 0.5, 0.5, 0.5));
+
+    assert!(Unit::try_normalize(Vector2::<f32>::zero()).is_none());
+    assert!(Unit::try_normalize(Vector2::new(f32::INFINITY, 1.0)).is_none());
+    assert!(Unit::try_normalize(Vector4::new(f64::NAN, 0.0, 0.0, 0.0)).is_none());
+}
+
+#[test]
+fn test_from_axis_angle() {
+    let axis = Unit::try_normalize(Vector3::new(1.0f64, 2.0, 3.0)).unwrap();
+    let q = Quaternion::from_axis_angle(axis, Deg(70.0));
+    assert_ulps_eq!(Matrix3::from(q), Matrix3::from_axis_angle(axis, Deg(70.0)));
+    assert_ulps_eq!(Basis3::from(q), Basis3::from_axis_angle(axis, Deg(70.0)));
+
+    let unit_q = Unit::<Quaternion<f64>>::from_axis_angle(axis, Deg(70.0));
+    assert_eq!(*unit_q, q);
+    assert_ulps_eq!(unit_q.magnitude(), 1.0);
+
+    assert_eq!(
+        Quaternion::from_axis_angle(Unit::unit_x(), Deg(30.0)),
+        Quaternion::from_angle_x(Deg(30.0))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize() {
+    let unit = Unit::new(Vector3::new(0.0f64, 0.6, 0.8)).unwrap();
+    let serialized = serde_json::to_string(&unit).unwrap();
+    let deserialized: Unit<Vector3<f64>> = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, unit);
+
+    let serialized = r#"{"value":{"x":0.0,"y":3.0,"z":4.0}}"#;
+    assert!(serde_json::from_str::<Unit<Vector3<f64>>>(serialized).is_err());
+}
@@ -350,3 +350,328 @@ fn test_cast() {
         Vector4::new(13.5f32, -4.6, -8.3, 2.41)
     );
 }
+
+#[test]
+fn test_component_wise() {
+    let v = Vector4::new(-1.5f64, 0.25, 2.0, -0.75);
+    assert_eq!(v.abs(), Vector4::new(1.5, 0.25, 2.0, 0.75));
+    assert_eq!(v.signum(), Vector4::new(-1.0, 1.0, 1.0, -1.0));
+    assert_eq!(v.floor(), Vector4::new(-2.0, 0.0, 2.0, -1.0));
+    assert_eq!(v.ceil(), Vector4::new(-1.0, 1.0, 2.0, -0.0));
+    assert_eq!(v.round(), Vector4::new(-2.0, 0.0, 2.0, -1.0));
+    assert_eq!(v.trunc(), Vector4::new(-1.0, 0.0, 2.0, -0.0));
+    assert_eq!(v.fract(), Vector4::new(0.5, 0.25, 0.0, 0.25));
+    assert_eq!(v.abs().sqrt(), v.abs().powf(0.5));
+    assert_eq!(v.recip(), Vector4::new(-1.0 / 1.5, 4.0, 0.5, -1.0 / 0.75));
+    assert_ulps_eq!(
+        Vector2::new(0.0f64, 1.0).exp(),
+        Vector2::new(1.0, f64::consts::E)
+    );
+
+    let w = Vector4::new(1.0, -1.0, 3.0, -0.5);
+    assert_eq!(v.min(w), Vector4::new(-1.5, -1.0, 2.0, -0.75));
+    assert_eq!(v.max(w), Vector4::new(1.0, 0.25, 3.0, -0.5));
+    assert_eq!(
+        v.clamp(Vector4::from_value(-1.0), Vector4::from_value(1.0)),
+        Vector4::new(-1.0, 0.25, 1.0, -0.75)
+    );
+    assert_eq!(
+        Vector3::new(1, -5, 3).clamp(Vector3::from_value(0), Vector3::from_value(2)),
+        Vector3::new(1, 0, 2)
+    );
+
+    let a = Vector2::new(0.0f64, 10.0);
+    let b = Vector2::new(1.0f64, 20.0);
+    assert_eq!(a.mix(b, Vector2::new(0.25, 0.5)), Vector2::new(0.25, 15.0));
+    assert_eq!(
+        v.step(Vector4::from_value(0.0)),
+        Vector4::new(0.0, 1.0, 1.0, 0.0)
+    );
+    assert_eq!(
+        Vector3::new(-1.0f64, 0.5, 3.0)
+            .smoothstep(Vector3::from_value(0.0), Vector3::from_value(1.0)),
+        Vector3::new(0.0, 0.5, 1.0)
+    );
+
+    assert_eq!(v.min_element(), -1.5);
+    assert_eq!(v.max_element(), 2.0);
+    assert_eq!(v.argmin(), 0);
+    assert_eq!(v.argmax(), 2);
+    assert_eq!(Vector3::new(4, 7, 7).argmax(), 1);
+    assert_eq!(Vector1::new(3).argmax(), 0);
+}
+
+#[test]
+fn test_component_wise_generic() {
+    fn saturate<V: VectorSpace<Scalar = f64> + ComponentWise<Element = f64>>(v: V) -> V {
+        v.clamp(V::zero(), V::from_value(1.0))
+    }
+    assert_eq!(saturate(Vector2::new(-0.5, 0.5)), Vector2::new(0.0, 0.5));
+    assert_eq!(
+        saturate(Vector3::new(2.0, 0.5, -1.0)),
+        Vector3::new(1.0, 0.5, 0.0)
+    );
+}
+
+#[test]
+fn test_reflect_refract() {
+    let normal = Vector3::unit_y();
+    let incident = Vector3::new(1.0f64, -1.0, 0.0).normalize();
+    assert_ulps_eq!(
+        incident.reflect(normal),
+        Vector3::new(1.0, 1.0, 0.0).normalize()
+    );
+    // Grazing and head-on rays.
+    assert_eq!(Vector3::unit_x().reflect(normal), Vector3::unit_x());
+    assert_eq!((-normal).reflect(normal), normal);
+
+    // Without a change of medium the ray passes straight through.
+    assert_ulps_eq!(incident.refract(normal, 1.0).unwrap(), incident);
+    // Entering a denser medium bends the ray towards the normal, following
+    // Snell's law.
+    let refracted = incident.refract(normal, 1.0 / 1.5).unwrap();
+    assert_ulps_eq!(refracted.magnitude(), 1.0);
+    assert_ulps_eq!(refracted.x, incident.x / 1.5);
+    // Leaving it at a shallow angle gives total internal reflection.
+    assert_eq!(incident.refract(normal, 1.5), None);
+    assert_eq!(Vector3::unit_x().refract(normal, 1.5), None);
+
+    assert_eq!(normal.faceforward(incident, normal), normal);
+    assert_eq!(normal.faceforward(-incident, normal), -normal);
+    assert_eq!(normal.faceforward(Vector3::unit_x(), normal), -normal);
+}
+
+#[test]
+fn test_plane_projection() {
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    assert_eq!(
+        v.project_on_plane(Vector3::unit_z()),
+        Vector3::new(1.0, 2.0, 0.0)
+    );
+    assert_eq!(
+        v.reject_from(Vector3::unit_x() * 2.0),
+        Vector3::new(0.0, 2.0, 3.0)
+    );
+    assert_ulps_eq!(v.project_on(v) + v.reject_from(v), v);
+    assert_eq!(v.reject_from(v), Vector3::zero());
+    assert_eq!(
+        Vector2::new(3.0f64, 4.0).reject_from(Vector2::unit_y()),
+        Vector2::new(3.0, 0.0)
+    );
+}
+
+#[test]
+fn test_orthonormal_basis() {
+    let normals = [
+        Vector3::unit_x(),
+        Vector3::unit_y(),
+        Vector3::unit_z(),
+        -Vector3::unit_z(),
+        Vector3::new(1.0, -2.0, 3.0).normalize(),
+        Vector3::new(1.0e-9, 1.0e-9, -1.0).normalize(),
+    ];
+    for &n in normals.iter() {
+        let (t, b) = n.orthonormal_basis();
+        assert_ulps_eq!(t.magnitude(), 1.0);
+        assert_ulps_eq!(b.magnitude(), 1.0);
+        assert_abs_diff_eq!(t.dot(b), 0.0, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(t.dot(n), 0.0, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(t.cross(b), n, epsilon = 1.0e-12);
+
+        let o = n.any_orthogonal();
+        assert_ulps_eq!(o.magnitude(), 1.0);
+        assert_abs_diff_eq!(o.dot(n), 0.0, epsilon = 1.0e-12);
+    }
+
+    // The input of `any_orthogonal` does not need to be normalized.
+    for &v in [
+        Vector3::new(5.0f64, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, -1.0e-3),
+    ]
+    .iter()
+    {
+        let o = v.any_orthogonal();
+        assert_ulps_eq!(o.magnitude(), 1.0);
+        assert_eq!(o.dot(v), 0.0);
+    }
+}
+
+#[test]
+fn test_slerp() {
+    let a = Vector2::unit_x();
+    let b = Vector2::new(0.0f64, 1.0);
+    let half = Vector2::new(1.0, 1.0).normalize();
+    assert_ulps_eq!(a.slerp(b, 0.0), a);
+    assert_ulps_eq!(a.slerp(b, 1.0), b);
+    assert_ulps_eq!(a.slerp(b, 0.5), half);
+    assert_ulps_eq!(a.nlerp(b, 0.5), half);
+    // Unlike `nlerp`, the angle changes at a constant rate.
+    assert_ulps_eq!(a.angle(a.slerp(b, 0.25)), Rad(f64::consts::FRAC_PI_8));
+    assert!(a.angle(a.nlerp(b, 0.25)) < Rad(f64::consts::FRAC_PI_8));
+
+    // Opposite vectors.
+    assert_ulps_eq!(a.slerp(-a, 0.5), b);
+    let v = Vector3::new(1.0f64, 2.0, 3.0).normalize();
+    let mid = v.slerp(-v, 0.5);
+    assert_ulps_eq!(mid.magnitude(), 1.0);
+    assert_abs_diff_eq!(mid.dot(v), 0.0, epsilon = 1.0e-12);
+    assert_ulps_eq!(v.slerp(-v, 1.0), -v);
+
+    // Nearly identical vectors.
+    let w = Vector3::new(1.0f64, 2.0, 3.0001).normalize();
+    assert_ulps_eq!(v.slerp(w, 0.5).magnitude(), 1.0);
+    assert_ulps_eq!(v.slerp(v, 0.5), v);
+
+    let x = Vector3::unit_x();
+    let z = Vector3::new(0.0f64, 0.0, 1.0);
+    assert_ulps_eq!(
+        x.slerp(z, 1.0 / 3.0),
+        Vector3::new(0.75f64.sqrt(), 0.0, 0.5)
+    );
+}
+
+#[test]
+fn test_rotate_towards() {
+    let a = Vector3::unit_x();
+    let b = Vector3::new(0.0f64, 1.0, 0.0);
+    let step = Rad(f64::consts::FRAC_PI_6);
+    let mut v = a;
+    for _ in 0..2 {
+        v = v.rotate_towards(b, step);
+    }
+    assert_ulps_eq!(v, Vector3::new(0.5, 0.75f64.sqrt(), 0.0));
+    v = v.rotate_towards(b, step);
+    assert_eq!(v, b);
+    assert_eq!(v.rotate_towards(b, step), b);
+
+    let a = Vector2::unit_x();
+    assert_ulps_eq!(
+        a.rotate_towards(-a, Rad(f64::consts::FRAC_PI_2)),
+        Vector2::new(0.0, 1.0)
+    );
+    assert_eq!(a.rotate_towards(-a, Rad(f64::consts::PI)), -a);
+}
+
+#[test]
+fn test_signed_angle() {
+    let a = Vector2::unit_x();
+    assert_ulps_eq!(
+        a.signed_angle(Vector2::new(0.0f64, 1.0)),
+        Rad(f64::consts::FRAC_PI_2)
+    );
+    assert_ulps_eq!(
+        a.signed_angle(Vector2::new(0.0f64, -1.0)),
+        -Rad(f64::consts::FRAC_PI_2)
+    );
+    assert_ulps_eq!(
+        a.signed_angle(Vector2::new(-1.0, 0.0)),
+        Rad(f64::consts::PI)
+    );
+
+    let x = Vector3::unit_x();
+    let y = Vector3::new(0.0f64, 1.0, 0.0);
+    assert_ulps_eq!(
+        x.signed_angle(y, Vector3::unit_z()),
+        Rad(f64::consts::FRAC_PI_2)
+    );
+    assert_ulps_eq!(
+        x.signed_angle(y, -Vector3::unit_z()),
+        -Rad(f64::consts::FRAC_PI_2)
+    );
+    assert_ulps_eq!(
+        y.signed_angle(x, Vector3::unit_z()),
+        -Rad(f64::consts::FRAC_PI_2)
+    );
+    // The axis does not need to be normalized or perpendicular.
+    assert_ulps_eq!(
+        x.signed_angle(y * 2.0, Vector3::new(1.0, 1.0, 0.5)),
+        Rad(f64::consts::FRAC_PI_2)
+    );
+    assert_eq!(x.signed_angle(x, Vector3::unit_z()), Rad(0.0));
+}
+
+#[test]
+fn test_bitwise() {
+    let a = Vector4::new(0b1100u32, 0b1010, 0xff, 0);
+    let b = Vector4::new(0b1010u32, 0b0110, 0x0f, 1);
+    assert_eq!(a & b, Vector4::new(0b1000, 0b0010, 0x0f, 0));
+    assert_eq!(a | b, Vector4::new(0b1110, 0b1110, 0xff, 1));
+    assert_eq!(a ^ b, Vector4::new(0b0110, 0b1100, 0xf0, 1));
+    assert_eq!(&a & 0b1000, Vector4::new(0b1000, 0b1000, 0b1000, 0));
+    assert_eq!(!Vector2::new(0u8, 0xf0), Vector2::new(0xff, 0x0f));
+    assert_eq!(!Vector2::new(0i32, -1), Vector2::new(-1, 0));
+
+    assert_eq!(a << 4, Vector4::new(0b1100_0000, 0b1010_0000, 0xff0, 0));
+    assert_eq!(a >> 2, Vector4::new(0b11, 0b10, 0x3f, 0));
+    assert_eq!(Vector2::new(-8i32, 8) >> 1, Vector2::new(-4, 4));
+    assert_eq!(
+        a << Vector4::new(0, 1, 4, 31),
+        Vector4::new(0b1100, 0b1_0100, 0xff0, 0)
+    );
+    assert_eq!(
+        a >> Vector4::new(2, 3, 4, 0),
+        Vector4::new(0b11, 0b1, 0xf, 0)
+    );
+
+    let mut c = a;
+    c &= b;
+    c |= Vector4::new(0, 0, 0x100, 0);
+    c ^= 1;
+    c <<= 1;
+    c >>= 2;
+    assert_eq!(c, Vector4::new(0b100, 0b001, 0x87, 0));
+    c <<= Vector4::new(1, 2, 0, 3);
+    c >>= Vector4::new(0, 1, 4, 0);
+    assert_eq!(c, Vector4::new(0b1000, 0b010, 0x8, 0));
+}
+
+#[test]
+fn test_wrapping_saturating() {
+    let a = Vector3::new(250u8, 10, 0);
+    let b = Vector3::new(10u8, 20, 1);
+    assert_eq!(a.wrapping_add(b), Vector3::new(4, 30, 1));
+    assert_eq!(a.wrapping_sub(b), Vector3::new(240, 246, 255));
+    assert_eq!(a.wrapping_mul(b), Vector3::new(196, 200, 0));
+    assert_eq!(a.saturating_add(b), Vector3::new(255, 30, 1));
+    assert_eq!(a.saturating_sub(b), Vector3::new(240, 0, 0));
+
+    let c = Vector2::new(i32::max_value(), i32::min_value());
+    assert_eq!(
+        c.wrapping_add(Vector2::new(1, -1)),
+        Vector2::new(i32::min_value(), i32::max_value())
+    );
+    assert_eq!(c.saturating_add(Vector2::new(1, -1)), c);
+}
+
+#[test]
+fn test_int_float_conversions() {
+    let v = Vector4::new(-1.5f32, -0.5, 0.5, 2.7);
+    assert_eq!(v.round_to_int::<i32>(), Vector4::new(-2, -1, 1, 3));
+    assert_eq!(v.floor_to_int::<i32>(), Vector4::new(-2, -1, 0, 2));
+    assert_eq!(v.ceil_to_int::<i32>(), Vector4::new(-1, 0, 1, 3));
+    assert_eq!(v.trunc_to_int::<i32>(), Vector4::new(-1, 0, 0, 2));
+
+    // Out of range values saturate like `as`.
+    let v = Vector4::new(-1.0f64, 300.0, f64::NAN, f64::INFINITY);
+    assert_eq!(v.round_to_int::<u8>(), Vector4::new(0, 255, 0, 255));
+    assert_eq!(
+        Vector1::new(-1.0e20f32).trunc_to_int::<i64>(),
+        Vector1::new(i64::min_value())
+    );
+
+    assert_eq!(
+        Vector3::new(1i32, -2, 3).to_float::<f32>(),
+        Vector3::new(1.0, -2.0, 3.0)
+    );
+    assert_eq!(
+        Vector1::new(u64::max_value()).to_float::<f64>(),
+        Vector1::new(u64::max_value() as f64)
+    );
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn test_shift_overflow() {
+    let _ = Vector2::new(1u8, 1) << Vector2::new(0, 8);
+}
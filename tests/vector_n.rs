@@ -0,0 +1,135 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "const-generics")]
+
+extern crate approx;
+extern crate cgmath;
+
+pub mod vector_n {
+    use cgmath::*;
+
+    #[test]
+    fn test_constructor() {
+        assert_eq!(VectorN::new([1, 2, 3]), VectorN::from_fn(|i| i as i32 + 1));
+        assert_eq!(VectorN::<i32, 5>::from_value(2).data, [2; 5]);
+        assert_eq!(VectorN::<f64, 4>::unit(2).data, [0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(VectorN::<f64, 6>::len(), 6);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = VectorN::new([1.0f64, 2.0, 3.0, 4.0, 5.0]);
+        let b = VectorN::new([5.0f64, 4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(a + b, VectorN::from_value(6.0));
+        assert_eq!(&a - &b, VectorN::new([-4.0, -2.0, 0.0, 2.0, 4.0]));
+        assert_eq!(-a * 2.0, VectorN::new([-2.0, -4.0, -6.0, -8.0, -10.0]));
+        assert_eq!(a.mul_element_wise(b), VectorN::new([5.0, 8.0, 9.0, 8.0, 5.0]));
+        assert_eq!(a.sum(), 15.0);
+        assert_eq!(a.product(), 120.0);
+        assert_eq!(vec![a, b].iter().sum::<VectorN<f64, 5>>(), a + b);
+
+        let mut c = a;
+        c += b;
+        c /= 2.0;
+        assert_eq!(c, VectorN::from_value(3.0));
+    }
+
+    #[test]
+    fn test_inner_space() {
+        let a = VectorN::new([1.0f64, 2.0, 3.0, 4.0, 5.0]);
+        let b = VectorN::new([5.0f64, 4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(a.dot(b), 35.0);
+        assert_eq!(a.magnitude2(), 55.0);
+        assert_ulps_eq!(a.normalize().magnitude(), 1.0);
+        assert_ulps_eq!(a.distance(a + VectorN::unit(3) * 2.0), 2.0);
+    }
+
+    #[test]
+    fn test_fixed_conversions() {
+        let v = Vector3::new(1, 2, 3);
+        let n: VectorN<i32, 3> = v.into();
+        assert_eq!(n.data, [1, 2, 3]);
+        assert_eq!(Vector3::from(n), v);
+
+        let r: &Vector3<i32> = n.as_ref();
+        assert_eq!(*r, v);
+
+        let v4 = Vector4::new(1.0f64, 2.0, 3.0, 4.0);
+        let n4 = VectorN::from(v4);
+        assert_eq!(n4.dot(n4), v4.dot(v4));
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_eq!(VectorN::new([1.5f64, 2.5]).cast::<i32>(), Some(VectorN::new([1, 2])));
+        assert_eq!(VectorN::new([-1i32, 300]).cast::<u8>(), None);
+    }
+}
+
+pub mod matrix_n {
+    use cgmath::*;
+
+    #[test]
+    fn test_mul() {
+        let a = MatrixNxM::<f64, 2, 3>::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        let b = a.transpose();
+        assert_eq!(a.row(1), VectorN::new([4.0, 5.0, 6.0]));
+        assert_eq!(b.row(0), VectorN::new([1.0, 4.0]));
+
+        let v = VectorN::new([1.0, 0.0, -1.0]);
+        assert_eq!(a * v, VectorN::new([-2.0, -2.0]));
+
+        let ab = a * b;
+        assert_eq!(ab, MatrixNxM::from([[14.0, 32.0], [32.0, 77.0]]));
+        assert_eq!(ab * MatrixNxM::identity(), ab);
+        assert_eq!(ab.trace(), 91.0);
+    }
+
+    #[test]
+    fn test_matches_fixed() {
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0))
+            * Matrix4::from_angle_y(Deg(30.0));
+        let n = MatrixNxM::from(m);
+        let v = Vector4::new(1.0f64, -2.0, 3.0, 1.0);
+
+        assert_ulps_eq!(Vector4::from(n * VectorN::from(v)), m * v);
+        assert_ulps_eq!(Matrix4::from(n * n), m * m);
+        assert_eq!(Matrix4::from(n.transpose()), m.transpose());
+
+        let r: &Matrix4<f64> = n.as_ref();
+        assert_eq!(*r, m);
+    }
+
+    #[test]
+    fn test_rect_conversions() {
+        let m = Matrix2x3::new(1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let n: MatrixNxM<f32, 3, 2> = m.into();
+        assert_eq!(n[1], VectorN::new([4.0, 5.0, 6.0]));
+        assert_eq!(Matrix2x3::from(n), m);
+        assert_eq!(Matrix3x2::from(n.transpose()), m.transpose());
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut m = MatrixNxM::<f64, 3, 3>::from_fn(|r, c| (r * 3 + c) as f64);
+        m.swap_columns(0, 2);
+        assert_eq!(m.row(0), VectorN::new([2.0, 1.0, 0.0]));
+        m.swap_rows(0, 1);
+        assert_eq!(m.row(0), VectorN::new([5.0, 4.0, 3.0]));
+        m.swap_elements((0, 0), (2, 2));
+        assert_eq!(m[0][0], 6.0);
+    }
+}
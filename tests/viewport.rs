@@ -0,0 +1,197 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64;
+
+fn viewport(y_axis: YAxis) -> Viewport<f64> {
+    Viewport {
+        x: 10.0,
+        y: 20.0,
+        width: 800.0,
+        height: 600.0,
+        min_depth: 0.0,
+        max_depth: 1.0,
+        y_axis: y_axis,
+    }
+}
+
+fn view() -> Matrix4<f64> {
+    Matrix4::look_at(
+        Point3::new(1.0, 2.0, 5.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    )
+}
+
+#[test]
+fn test_ndc_to_window() {
+    let gl = viewport(YAxis::Up);
+    let top_left = Point3::new(-1.0, 1.0, -1.0);
+    assert_eq!(
+        gl.ndc_to_window(top_left, ClipSpace::OPENGL),
+        Point3::new(10.0, 620.0, 0.0)
+    );
+    assert_eq!(
+        gl.ndc_to_window(Point3::new(0.0, 0.0, 0.0), ClipSpace::OPENGL),
+        Point3::new(410.0, 320.0, 0.5)
+    );
+
+    // Vulkan has y down in both NDC and window coordinates, Direct3D only in
+    // window coordinates.
+    let top_left = Point3::new(-1.0, -1.0, 0.0);
+    let vk = viewport(YAxis::Down);
+    assert_eq!(
+        vk.ndc_to_window(top_left, ClipSpace::VULKAN),
+        Point3::new(10.0, 20.0, 0.0)
+    );
+    let top_left = Point3::new(-1.0, 1.0, 0.0);
+    assert_eq!(
+        vk.ndc_to_window(top_left, ClipSpace::DIRECT3D),
+        Point3::new(10.0, 20.0, 0.0)
+    );
+
+    let p = Point3::new(0.25, -0.5, 0.75);
+    for &clip in &[ClipSpace::OPENGL, ClipSpace::VULKAN, ClipSpace::DIRECT3D] {
+        for &y_axis in &[YAxis::Up, YAxis::Down] {
+            let v = viewport(y_axis);
+            assert_ulps_eq!(v.window_to_ndc(v.ndc_to_window(p, clip), clip), p);
+        }
+    }
+}
+
+#[test]
+fn test_project_unproject() {
+    let point = Point3::new(0.5, -0.25, 1.0);
+    for &clip in &[
+        ClipSpace::OPENGL,
+        ClipSpace::VULKAN,
+        ClipSpace::WEBGPU.reversed(),
+    ] {
+        let proj = clip.perspective(Deg(60.0), 4.0 / 3.0, 0.1, 100.0);
+        let v = viewport(YAxis::Down);
+
+        let window = v.project(point, view(), proj, clip).unwrap();
+        assert!(window.x > 10.0 && window.x < 810.0);
+        assert!(window.y > 20.0 && window.y < 620.0);
+        assert!(window.z > 0.0 && window.z < 1.0);
+        assert_relative_eq!(
+            v.unproject(window, view(), proj, clip).unwrap(),
+            point,
+            epsilon = 1.0e-9
+        );
+    }
+
+    // The eye itself projects to infinity.
+    let proj = perspective(Deg(60.0), 1.0, 0.1, 100.0);
+    let eye = Point3::new(1.0, 2.0, 5.0);
+    assert_eq!(
+        viewport(YAxis::Up).project(eye, view(), proj, ClipSpace::OPENGL),
+        None
+    );
+    assert_eq!(
+        viewport(YAxis::Up).unproject(eye, view(), Matrix4::zero(), ClipSpace::OPENGL),
+        None
+    );
+}
+
+#[test]
+fn test_pick_ray() {
+    let eye = Point3::new(1.0, 2.0, 5.0);
+    let point = Point3::new(0.5, -0.25, 1.0);
+    for &clip in &[ClipSpace::OPENGL, ClipSpace::VULKAN.reversed()] {
+        for &far in &[100.0, f64::INFINITY] {
+            let proj = clip.perspective(Deg(60.0), 4.0 / 3.0, 0.1, far);
+            let v = viewport(YAxis::Up);
+            let window = v.project(point, view(), proj, clip).unwrap();
+
+            let (origin, direction) = v
+                .pick_ray(Point2::new(window.x, window.y), view(), proj, clip)
+                .unwrap();
+            assert_relative_eq!(direction, (point - eye).normalize(), epsilon = 1.0e-9);
+            assert_relative_eq!((origin - eye).normalize(), direction, epsilon = 1.0e-9);
+        }
+    }
+
+    // An orthographic ray points straight ahead.
+    let proj = ortho(-4.0, 4.0, -3.0, 3.0, 0.1, 100.0);
+    let (origin, direction) = viewport(YAxis::Up)
+        .pick_ray(
+            Point2::new(10.0, 20.0),
+            Matrix4::identity(),
+            proj,
+            ClipSpace::OPENGL,
+        )
+        .unwrap();
+    assert_relative_eq!(origin, Point3::new(-4.0, -3.0, -0.1), epsilon = 1.0e-9);
+    assert_relative_eq!(direction, -Vector3::unit_z(), epsilon = 1.0e-9);
+}
+
+#[test]
+fn test_screen_ortho() {
+    let screen = ScreenOrtho {
+        width: 800.0f64,
+        height: 600.0,
+    };
+    for &clip in &[ClipSpace::OPENGL, ClipSpace::VULKAN, ClipSpace::DIRECT3D] {
+        // The screen position maps back to itself through a top-left,
+        // y-down viewport.
+        let v = Viewport {
+            x: 0.0,
+            y: 0.0,
+            width: 800.0,
+            height: 600.0,
+            min_depth: 0.0,
+            max_depth: 1.0,
+            y_axis: YAxis::Down,
+        };
+        let m4 = screen.to_matrix(clip);
+        let m3 = screen.to_matrix3(clip);
+        for &(x, y) in &[(0.0, 0.0), (800.0, 600.0), (120.0, 450.0)] {
+            let p = v
+                .project(Point3::new(x, y, 0.0), Matrix4::identity(), m4, clip)
+                .unwrap();
+            assert_relative_eq!(p, Point3::new(x, y, 0.5), epsilon = 1e-12);
+
+            let ndc = m3 * Vector3::new(x, y, 1.0);
+            let expected = v.window_to_ndc(Point3::new(x, y, 0.0), clip);
+            assert_relative_eq!(
+                ndc,
+                Vector3::new(expected.x, expected.y, 1.0),
+                epsilon = 1e-12
+            );
+        }
+    }
+    assert_eq!(Matrix4::from(screen), screen.to_matrix(ClipSpace::OPENGL));
+}
+
+#[test]
+fn test_pixel_helpers() {
+    let p = Point2::new(10.3f64, 7.8);
+    assert_eq!(logical_to_physical(p, 2.0), Point2::new(20.6, 15.6));
+    assert_eq!(physical_to_logical(Point2::new(20.6, 15.6), 2.0), p);
+
+    assert_eq!(snap_to_pixel(p, 1.0), Point2::new(10.0, 8.0));
+    assert_eq!(snap_to_pixel(p, 2.0), Point2::new(10.5, 8.0));
+    assert_eq!(snap_to_pixel_center(p, 1.0), Point2::new(10.5, 7.5));
+    assert_eq!(snap_to_pixel_center(p, 2.0), Point2::new(10.25, 7.75));
+    assert_eq!(
+        snap_to_pixel_center(p, 1.5),
+        Point2::new(31.0 / 3.0, 23.0 / 3.0)
+    );
+}